@@ -0,0 +1,12 @@
+print("Hello, world!")
+m = [[101.2, 202.12]]
+print("\n".join(str(row) for row in m))
+
+print("### Arrays")
+xs = [1, 2, 3, 4, 5]
+ys = [0] * 500
+
+print("First", xs[0], ys[0])
+print("Len:", len(xs), len(ys))
+print("Slice xs:", xs[1:3])
+print("Slice ys:", ys[1:3])
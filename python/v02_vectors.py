@@ -0,0 +1,17 @@
+print("### Vectors")
+xs = [1, 2, 3]
+print("Initial vector", xs)
+xs.append(4)
+print("Added 4:", xs)
+print("Slice:", xs[1:3])
+print(f"Pop last element Some({xs.pop()})")
+for x in xs:
+    print(">", x)
+
+for i, x in enumerate(xs):
+    print(f"Enumerate pos: {i} val: {x}")
+
+xs = [x * 3 for x in xs]
+print("Multiplied vector:", xs)
+
+print("Collected iterator:", list(range(10)))
@@ -0,0 +1,114 @@
+import re
+import unicodedata
+from string import ascii_lowercase, capwords
+
+
+def naive_capitalize(s):
+    return s[:1].upper() + s[1:]
+
+
+def ascii_capitalize(s):
+    return (s[:1].upper() if s[:1].isascii() else s[:1]) + s[1:]
+
+
+def words(s):
+    # separators, fooBar and the end of an acronym in HTTPServer
+    return re.findall(r"[A-Z]+(?=[A-Z][a-z])|[A-Z]?[a-z0-9]+|[A-Z0-9]+", s)
+
+
+print("### Strings")
+alice = "I like dogs"
+bob = alice.replace("dog", "cat")
+print("Alice says:", alice)
+print("Bob says:", bob)
+
+panagram = "the quick brown fox jumps over the lazy"
+print("Panagram:", panagram)
+print("not a Latin pangram, missing:", ", ".join(sorted(set(ascii_lowercase) - set(panagram))))
+greek = "Ξεσκεπάζω την ψυχοφθόρα βδελυγμία"
+# accents stripped and final sigma as sigma, like pangram::analyze
+letters = {c for c in unicodedata.normalize("NFD", greek.lower()) if c.isalpha()} | {"σ"}
+missing = sorted(set("αβγδεζηθικλμνξοπρστυφχψω") - letters)
+print(f"{greek}:", "Greek pangram" if not missing else "missing " + ", ".join(missing))
+
+print("Words in reverse")
+for word in reversed(panagram.split()):
+    print(">", word)
+
+string = "".join(c + ", " for c in sorted(set(panagram)))
+print("Used    chars:", string)
+print("Trimmed chars:", string.strip(" ,"))
+
+composed = "no\u00ebl"
+decomposed = "noe\u0308l"
+normalized = unicodedata.normalize("NFC", composed) == unicodedata.normalize("NFC", decomposed)
+print(f"{composed} == {decomposed}: {str(composed == decomposed).lower()}, normalized: {str(normalized).lower()}")
+print("ﬁ == fi compatible:", str(unicodedata.normalize("NFKC", "ﬁ") == "fi").lower())
+# no graphemes in the std lib, combining marks are kept with the char before them
+graphemes = []
+for c in decomposed:
+    if graphemes and unicodedata.combining(c):
+        graphemes[-1] += c
+    else:
+        graphemes.append(c)
+print(f"{decomposed} reversed by char: {decomposed[::-1]}, by grapheme: {''.join(reversed(graphemes))}")
+
+for name in ["oleg", "óleg", "¶πø^ä", "ǆungla", "ßa"]:
+    print(name, "->", naive_capitalize(name))
+    print(name, "->", ascii_capitalize(name))
+    print(name, "->", name.capitalize())
+
+phrase = "they're bill's friends from the UK"
+print("title:", phrase.title())
+print("capwords:", capwords(phrase))
+identifier = "parseHTTPResponse code"
+parts = words(identifier)
+print(
+    identifier,
+    "->",
+    "_".join(w.lower() for w in parts),
+    "-".join(w.lower() for w in parts),
+    parts[0].lower() + "".join(w.capitalize() for w in parts[1:]),
+    "".join(w.capitalize() for w in parts),
+)
+
+# a Rust String is 24 bytes (pointer, capacity, length) plus the utf-8 bytes
+for s in ["Hello", "Héllǒ"]:
+    data = s.encode()
+    print(f"'{s}': length: {len(data)}, chars: {len(s)}, memsize: {24 + len(data)}")
+    print(list(data))
+    if s == "Hello":
+        print(f'after e: "{s[2:]}"')
+
+# utf8::inspect, Python strings don't expose their bytes so they're encoded one char at a time
+print(f"{'code':<9} {'char':<5} {'bytes':<6} {'hex':<12} binary (prefix|bits)")
+offset, starts = 0, set()
+for c in "Héllǒ":
+    data = c.encode()
+    bits = []
+    for b in data:
+        binary = f"{b:08b}"
+        prefix = len(binary) - len(binary.lstrip("1")) + 1
+        bits.append(binary[:prefix] + "|" + binary[prefix:])
+    hexed = " ".join(f"{b:02X}" for b in data)
+    span = f"{offset}..{offset + len(data)}"
+    print(f"U+{ord(c):04X}    {c:<5} {span:<6} {hexed:<12} {' '.join(bits)}")
+    starts.add(offset)
+    offset += len(data)
+inside = [str(i) for i in range(offset) if i not in starts]
+print("not char boundaries:", ", ".join(inside))
+print("&uni[2..]: byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3) of `Héllǒ`")
+# slicing::slice, Python has no grapheme slicing and bytes have to be decoded again
+uni = "Héllǒ"
+print("uni[2:] by char:", uni[2:])
+print("uni[::-1] by char:", uni[::-1])
+print("uni[-1:] by grapheme:", uni[-1:])
+print("uni[-2:] by byte:", uni.encode()[-2:].decode())
+try:
+    uni.encode()[2:].decode()
+except UnicodeDecodeError:
+    print("uni[2:] by byte: error, byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3) of `Héllǒ`")
+
+print("many\n    lines")
+print(' blah """ asd \'\nnewline \n\n\n\ntest\n')
+print("a")
@@ -0,0 +1,23 @@
+def do_something(x):
+    def do_something_else(x):
+        return x * 3
+
+    return do_something_else(x)
+
+
+print(do_something(3))
+
+example_closure = lambda x: x
+print(example_closure("Hello"), example_closure(str(5)))
+
+x = [1, 2, 3]
+equal_to_x = lambda z: z == x
+assert equal_to_x([1, 2, 3])
+
+items = [1, 2, 3, 4, 5]
+plus_one = [x + 1 for x in items]
+sum_all = sum(x + 1 for x in items)
+print(plus_one, sum_all)
+
+two_args = lambda x, y: x - y
+print(two_args(5, 3))
@@ -0,0 +1,30 @@
+def fizz_buzz(x, rules=((3, "Fizz"), (5, "Buzz"))):
+    # rules are checked in order, 15 matches both and becomes FizzBuzz
+    return "".join(word for divisor, word in rules if x % divisor == 0) or str(x)
+
+
+def load_rules(path):
+    rules, start, end = [], 1, 100
+    with open(path) as f:
+        for line in f:
+            line = line.strip()
+            if not line or line.startswith("#"):
+                continue
+            key, word = (part.strip() for part in line.split("=", 1))
+            if key == "range":
+                start, end = word.split("..")
+                start, end = int(start), int(end[1:]) if end.startswith("=") else int(end) - 1
+            else:
+                rules.append((int(key), word))
+    return rules, range(start, end + 1)
+
+
+print("---- FizzBuzz Start ----")
+for i in range(1, 16):
+    print(fizz_buzz(i))
+print("----")
+list(map(print, map(fizz_buzz, range(1, 16))))
+
+rules, numbers = load_rules("./data/fizzbuzz.txt")
+print(" ".join(fizz_buzz(i, rules) for i in numbers))
+print("---- FizzBuzz End ----")
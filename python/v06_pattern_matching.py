@@ -0,0 +1,136 @@
+import csv
+import json
+from dataclasses import dataclass, field
+
+
+@dataclass
+class Dog:
+    name: str
+    age: int = 0
+    weight: float = 0.0
+    tags: list = field(default_factory=list)
+
+
+@dataclass
+class Cat:
+    name: str
+    age: int = 0
+    weight: float = 0.0
+    tags: list = field(default_factory=list)
+
+
+@dataclass
+class Bird:
+    species: str
+    name: str
+    age: int = 0
+    weight: float = 0.0
+    tags: list = field(default_factory=list)
+
+
+@dataclass
+class Fish:
+    species: str
+    name: str
+    age: int = 0
+    weight: float = 0.0
+    tags: list = field(default_factory=list)
+
+
+@dataclass
+class Other:
+    species: str
+    name: str
+    age: int = 0
+    weight: float = 0.0
+    tags: list = field(default_factory=list)
+
+
+KINDS = {"dog": Dog, "cat": Cat, "bird": Bird, "fish": Fish, "other": Other}
+
+
+def classify(animal):
+    match animal:
+        case Dog(name=name, age=0):
+            return "puppy", f"A puppy named: {name}"
+        case Dog(name=name, tags=tags) if "guide" in tags:
+            return "guide dog", f"A guide dog named: {name}"
+        case Dog(name=name) if name.startswith(("Sir ", "Lady ")):
+            return "noble dog", f"A dog of noble birth: {name}"
+        case Dog(name=name, age=age) if age >= 10:
+            return "senior dog", f"A senior dog named: {name}"
+        case Dog(name=name):
+            return "dog", f"A dog named: {name}"
+        case Cat(name=name, age=0):
+            return "kitten", f"A kitten named: {name}"
+        case Cat(name=name, weight=weight) if weight > 6.0:
+            return "big cat", f"A big cat named: {name}, {weight:.1f} kg"
+        case Cat(age=age):
+            return "cat", f"A cat aged: {age}"
+        case Bird(species=species, name=name, tags=tags) if "talks" in tags:
+            return "talking bird", f"A {species} that talks, named: {name}"
+        case Bird(species=species, name=name):
+            return "bird", f"A {species} named: {name}"
+        case Fish(species=species, weight=weight) if weight >= 100.0:
+            return "big fish", f"A {species} of {weight:.0f} kg"
+        case Fish(species=species, name=name):
+            return "fish", f"A {species} named: {name}"
+        case Other(species=species, name=name, age=age):
+            return "other", f"A {species} aged: {age}, named: {name}"
+
+
+def species(animal):
+    return getattr(animal, "species", type(animal).__name__.lower())
+
+
+def read_csv(path):
+    with open(path, newline="") as f:
+        for row in csv.DictReader(f):
+            kind = KINDS[row.pop("kind")]
+            if kind in (Dog, Cat):
+                row.pop("species")
+            tags = [tag.strip() for tag in row.pop("tags").split(";") if tag.strip()]
+            yield kind(**row | {"age": int(row["age"]), "weight": float(row["weight"]), "tags": tags})
+
+
+def read_json(path):
+    with open(path) as f:
+        for entry in json.load(f):
+            yield KINDS[entry.pop("kind")](**entry)
+
+
+def number(x):
+    match x:
+        case 1:
+            print("one")
+        case 2 | 3:
+            print("two or three")
+        case x if x >= 4:
+            print("four or bigger")
+        case _:
+            print("anything")
+
+
+RULES = [(3, "Fizz"), (5, "Buzz")]
+
+
+def fizz_buzz(x):
+    print("".join(word for divisor, word in RULES if x % divisor == 0) or x)
+
+
+print("---- Pattern Matching Start ----")
+for x in range(6):
+    number(x)
+print(classify(Dog(name="Fido", age=3, weight=12.5))[1])
+print(classify(Cat(name="Tom", age=3, weight=4.2))[1])
+
+for animal in read_csv("./data/animals.csv"):
+    label, text = classify(animal)
+    print(f"{label:<12} {text}")
+for animal in read_json("./data/animals.json"):
+    if animal.weight > 100.0:
+        print(f"A heavy {species(animal)} named: {animal.name}")
+
+for x in range(1, 24):
+    fizz_buzz(x)
+print("---- Pattern Matching End ----")
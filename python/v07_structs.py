@@ -0,0 +1,52 @@
+from dataclasses import dataclass, replace
+
+
+@dataclass
+class A:
+    x: int
+    y: int
+
+
+@dataclass
+class D:
+    x: int = 0
+    y: int = 0
+    z: int = 0
+
+    def __repr__(self):
+        return f"D {{ x: {self.x}, y: {self.y}, z: {self.z} }}"
+
+
+print("---- Structs Start ----")
+a = A(x=1, y=2)
+a.x += 2
+a_updated = replace(a, y=4)
+print("a.x", a.x, "a_updated.x", a_updated.x)
+print("a.y", a.y, "a_updated.y", a_updated.y)
+
+d1 = D(1, 2)
+d2 = D(x=1, y=2)
+print(d1)
+print(d2)
+
+
+def build_d(x, y, z=0):
+    # keyword-only arguments would raise TypeError for a missing x or y, but only at runtime
+    violations = []
+    if x < 0:
+        violations.append(f"x must not be negative, got {x}")
+    if y < 0:
+        violations.append(f"y must not be negative, got {y}")
+    if z > 100:
+        violations.append(f"z must be at most 100, got {z}")
+    if violations:
+        raise ValueError("invalid D: " + "; ".join(violations))
+    return D(x, y, z)
+
+
+print(f"Ok({build_d(1, 2, z=3)})")
+try:
+    build_d(-1, -2, z=300)
+except ValueError as e:
+    print(e)
+print("---- Structs End ----")
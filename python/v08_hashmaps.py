@@ -0,0 +1,78 @@
+# dicts keep insertion order, so does the Rust version's Dict, its HashMap gets sorted
+import csv
+from collections import Counter, defaultdict
+
+
+def debug(d):
+    # Rust's Debug output uses double quotes
+    items = ", ".join(f'"{k}": {v!r}'.replace("'", '"') for k, v in d.items())
+    return "{" + items + "}"
+
+
+def tally(counter):
+    return "Tally(" + debug(dict(counter.most_common())) + ")"
+
+
+def countries(path):
+    with open(path, newline="") as f:
+        return [row["Country"] for row in csv.DictReader(f)]
+
+
+def some(value):
+    return "None" if value is None else f"Some({value})"
+
+
+print("---- HashMaps Start ----")
+literal = dict([("key", "value"), ("blah", "blubb")])
+print(f'"{literal["key"]}"')
+
+mutable = {}
+mutable["one"] = 1
+mutable["two"] = 2
+del mutable["one"]
+print(some(mutable.get("one")))
+print(some(mutable.get("two")))
+
+mutable["three"] = 3
+print(repr(sorted(mutable.items())).replace("'", '"'))
+
+print(len({"a": 1, "b": 2}))
+
+literal = dict([("key", "value"), ("blah", "blubb")])
+print(debug(literal))
+
+mutable = {}
+mutable["one"] = 1
+mutable["two"] = 2
+del mutable["one"]
+print(some(mutable.get("one")))
+print(some(mutable.get("two")))
+
+mutable["three"] = 3
+for k, v in mutable.items():
+    print(f"{k}: {v}")
+
+print(debug({"a": 1, "b": 2}))
+
+print("get with default:", mutable.get("four", 4))
+mutable["four"] = mutable.setdefault("four", 0) + 4
+mutable.update({"two": 22, "five": 5})
+print(debug(mutable))
+key, value = mutable.popitem()
+print(f'Some(("{key}", {value}))')
+print(some(mutable.pop("two", None)))
+print("{" + ", ".join(f"{k}: {v}" for k, v in mutable.items()) + "}")
+
+earlier = Counter(countries("./data/sales.csv"))
+later = Counter(countries("./data/sales_2023.csv"))
+print("countries:", tally(earlier))
+print("most common:", repr(earlier.most_common(1)).replace("'", '"'))
+print("with 2023:", tally(earlier + later))
+print("only 2023:", tally(later - earlier))
+
+amounts = defaultdict(list)
+with open("./data/sales.csv", newline="") as f:
+    for row in csv.DictReader(f):
+        amounts[row["Country"]].append(float(row["Amount"]))
+print("amounts:", debug(amounts))
+print("---- HashMaps End ----")
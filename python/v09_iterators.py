@@ -0,0 +1,65 @@
+from itertools import accumulate, islice, pairwise
+
+
+def trace(label, iterable):
+    # prints before every yield, like the Rust version's trace()
+    for x in iterable:
+        print(f"{label}: next() -> Some({x})")
+        yield x
+    print(f"{label}: next() -> None")
+
+
+def count_to(end):
+    count = 0
+    while count < end:
+        count += 1
+        yield count
+
+
+def fibonacci():
+    a, b = 0, 1
+    while True:
+        yield a
+        a, b = b, a + b
+
+
+def debug(values):
+    # Rust's Debug output uses double quotes for strings
+    return repr(values).replace("'", '"')
+
+
+print("---- Iterators Start ----")
+countdown = range(10, 0, -3)
+print("len:", len(countdown))
+print(f"front: Some({countdown[0]}), back: Some({countdown[-1]})")
+print("middle:", list(countdown[1:-1]))
+print("reversed:", list(reversed(countdown)))
+
+lazy = (x * 10 for x in trace("lazy", range(1, 4)))
+print("built the chain, nothing traced yet")
+print("took:", list(islice(lazy, 2)))
+
+eager = list(trace("eager", range(1, 4)))
+print("collected:", eager)
+
+names = ["ann", "bob"]
+lengths = [len(name) for name in names]
+print("iter:", lengths, "still has", debug(names))
+names[:] = [name.upper() for name in names]
+print("iter_mut:", debug(names))
+owned = [name + "!" for name in names]
+print("into_iter:", debug(owned))
+
+evens = trace("filter", (x for x in trace("counter", range(1, 7)) if x % 2 == 0))
+total = sum(trace("map", (x * x for x in evens)))
+print("sum of even squares:", total)
+
+sums = list(islice(accumulate(trace("counter", range(1, 100))), 3))
+print("running sums:", sums)
+print("pairs:", list(pairwise("abc")))
+
+print("generated:", list(count_to(5)))
+print("fibonacci:", list(islice(fibonacci(), 10)))
+first_big = next(n for n in trace("fibonacci", fibonacci()) if n > 20)
+print(f"first above 20: Some({first_big})")
+print("---- Iterators End ----")
@@ -0,0 +1,155 @@
+import ast
+import traceback
+
+I32_MIN, I32_MAX = -(2**31), 2**31 - 1
+
+
+class DoubleError(Exception):
+    pass
+
+
+class EmptyVec(DoubleError):
+    def __str__(self):
+        return "no first item"
+
+
+class ExprError(Exception):
+    # `debug` mirrors Rust's {:?}, str() mirrors its Display
+    def __init__(self, debug, message):
+        super().__init__(message)
+        self.debug = debug
+
+
+def overflow():
+    return ExprError("Overflow", "arithmetic overflow")
+
+
+def evaluate(text, number=int):
+    """Python's eval, limited to numbers and + - * / like the Rust evaluator"""
+
+    def checked(value):
+        if number is int and not I32_MIN <= value <= I32_MAX:
+            raise overflow()
+        return value
+
+    def walk(node):
+        match node:
+            case ast.Constant(value=int() | float() as value):
+                return checked(number(value))
+            case ast.UnaryOp(op=ast.USub(), operand=operand):
+                return checked(-walk(operand))
+            case ast.BinOp(left=left, op=op, right=right):
+                a, b = walk(left), walk(right)
+                match op:
+                    case ast.Add():
+                        return checked(a + b)
+                    case ast.Sub():
+                        return checked(a - b)
+                    case ast.Mult():
+                        return checked(a * b)
+                    case ast.Div() if b == 0:
+                        raise ExprError("DivisionByZero", "division by zero")
+                    case ast.Div() if number is int:
+                        # Rust truncates towards zero, // rounds down
+                        sign = -1 if (a < 0) != (b < 0) else 1
+                        return checked(sign * (abs(a) // abs(b)))
+                    case ast.Div():
+                        return a / b
+        found = text[node.col_offset]
+        column = node.col_offset + 1
+        raise ExprError(
+            f"BadToken {{ column: {column}, found: '{found}' }}",
+            f"unexpected character '{found}' at column {column}",
+        )
+
+    return walk(ast.parse(text, mode="eval").body)
+
+
+def result(f):
+    try:
+        return f"Ok({f()})"
+    except ExprError as e:
+        return f"Err({e.debug})"
+
+
+def give_commoner(gift):
+    match gift:
+        case "snake":
+            print("Yuck, I'm putting this snake back")
+        case None:
+            print("No gift? Oh well")
+        case inner:
+            print(f"{inner}? How nice")
+
+
+def give_royal(gift):
+    assert gift is not None
+    if gift == "snake":
+        raise RuntimeError("AaaaAaaAA!1")
+    print(f"I love {gift}s")
+
+
+def multiply(first, second):
+    first, second = evaluate(first), evaluate(second)
+    if not I32_MIN <= first * second <= I32_MAX:
+        raise overflow()
+    return first * second
+
+
+def double_first(items):
+    if not items:
+        raise EmptyVec()
+    try:
+        first = evaluate(items[0])
+    except ExprError as e:
+        error = DoubleError("invalid first item")
+        error.add_note(f'parsing the first item "{items[0]}"')
+        raise error from e
+    return multiply(str(first), "2")
+
+
+def chain(error):
+    # same as {:#} in Rust, notes are the wrap_err contexts, outermost first
+    messages = list(reversed(getattr(error, "__notes__", [])))
+    while error is not None:
+        messages.append(str(error))
+        error = error.__cause__
+    return ": ".join(messages)
+
+
+def show(double):
+    try:
+        print("The first double is", double())
+    except Exception as e:
+        print("Error:", chain(e))
+        traceback.print_exc()
+
+
+def double_strings(strings):
+    try:
+        return double_first(strings)
+    except DoubleError as e:
+        e.add_note("doubling the first of the strings")
+        raise
+
+
+print("---- Error handling Start ----")
+give_commoner("cabbage")
+give_commoner("snake")
+give_commoner(None)
+give_royal("robin")
+
+print("Twenty:", result(lambda: multiply("10", "2")))
+print("t * 2:", result(lambda: multiply("t", "2")))
+print("i32::MAX * 2:", result(lambda: multiply("2147483647", "2")))
+
+print(
+    f"10 * (2 + 3) / 4 = {result(lambda: evaluate('10 * (2 + 3) / 4'))} as i32, "
+    f"{result(lambda: evaluate('10 * (2 + 3) / 4', float))} as f64"
+)
+print("1 / 0 =", result(lambda: evaluate("1 / 0")))
+
+show(lambda: double_first(["42", "93", "18"]))
+show(lambda: double_first([]))
+show(lambda: double_strings(["tofu", "93", "18"]))
+print("---- Error handling End ----")
@@ -0,0 +1,161 @@
+from abc import ABC, abstractmethod
+from contextlib import ExitStack
+from dataclasses import dataclass
+
+
+@dataclass
+class D:
+    x: int = 0
+    y: int = 0
+
+    def __add__(self, other):
+        # None where the Rust version's i32 would overflow
+        d = D(self.x + other.x, self.y + other.y)
+        return d if all(-(2**31) <= n < 2**31 for n in (d.x, d.y)) else None
+
+    def __repr__(self):
+        return f"D {{ x: {self.x}, y: {self.y} }}"
+
+
+class Person:
+    def name(self):
+        return "Unnamed"
+
+
+class Student(Person, ABC):
+    @abstractmethod
+    def university(self): ...
+
+
+class Programmer(ABC):
+    @abstractmethod
+    def fav_language(self): ...
+
+
+class CompSciStudent(Programmer, Student, ABC):
+    @abstractmethod
+    def git_username(self): ...
+
+
+class StreetPerson(Person):
+    def __init__(self, name):
+        self._name = name
+
+
+class RustProgrammer(Programmer, Person):
+    def __init__(self, name):
+        self._name = name
+
+    def fav_language(self):
+        return "Rust"
+
+
+class CollegeStudent(CompSciStudent):
+    def __init__(self, name):
+        self._name = name
+
+    def name(self):
+        return self._name
+
+    def fav_language(self):
+        return "From Python to Rust"
+
+    def university(self):
+        return "Community college"
+
+    def git_username(self):
+        return self._name.lower()
+
+
+class Connection:
+    def __init__(self, name, log):
+        self.name = name
+        self.log = log
+
+    def __enter__(self):
+        self.log.append(f"open {self.name}")
+        return self.name
+
+    def __exit__(self, exc_type, exc, tb):
+        self.log.append(f"close {self.name}: {'ok' if exc is None else f'error: {exc}'}")
+        return False
+
+
+def parse_int(text):
+    # same message as Rust's ParseIntError
+    if not text.isdigit():
+        raise ValueError("invalid digit found in string")
+    return int(text)
+
+
+def debug(values):
+    return repr(values).replace("'", '"')
+
+
+def make_adder_function(y):
+    return lambda x: x + y
+
+
+def make_person(rnd):
+    if rnd <= 3:
+        return StreetPerson("Bob")
+    if rnd <= 6:
+        return CollegeStudent("Jake")
+    if rnd <= 9:
+        return RustProgrammer("Don")
+    return StreetPerson("Someone")
+
+
+def comp_sci_student_greeting(student):
+    return (
+        f"Name is {student.name()} I attend {student.university()} "
+        f"Fav lang is {student.fav_language()} git username is {student.git_username()}"
+    )
+
+
+def comp_sci_vs_programmer(student, programmer):
+    print(f"Programmer named {programmer.name()} loves {programmer.fav_language()}")
+    return comp_sci_student_greeting(student)
+
+
+print("---- Traits Start ----")
+print(f"D sums: Some({D(x=3) + D(x=3, y=5)})")
+# ints don't overflow in Python, the limit means two Ds can always be added in Rust
+LIMIT = (2**31 - 1) // 2
+violations = [f"{name} must be within ±{LIMIT}, got {value}" for name, value in [("x", 2**31 - 1), ("y", -(2**31))] if abs(value) > LIMIT]
+print("invalid D: " + "; ".join(violations))
+half = D(x=LIMIT)
+total = half + half
+print("D overflows:", "None" if total is None or total + half is None else f"Some({total + half})")
+
+plus_one = make_adder_function(1)
+assert plus_one(2) == 3
+
+print("Person:", make_person(8).name())
+
+cs = CollegeStudent("Bert")
+print(comp_sci_student_greeting(cs))
+print(comp_sci_student_greeting(cs))
+print(comp_sci_vs_programmer(cs, RustProgrammer("Bob")))
+
+# ExitStack runs callbacks in reverse like Rust drops values
+events = []
+with ExitStack() as stack:
+    stack.callback(events.append, "first")
+    stack.callback(events.append, "deferred")
+    counter = iter(range(1, 6))
+    next(counter)
+    stack.callback(events.append, "Counter dropped at 2")
+    stack.callback(events.append, "second")
+print("drop order:", debug(events))
+
+log = []
+for name, query in [("db", "42"), ("cache", "forty-two")]:
+    try:
+        with Connection(name, log):
+            answer = parse_int(query)
+        log.append(f"answer: {answer}")
+    except ValueError:
+        log.append("no answer")
+print("with:", debug(log))
+print("---- Traits End ----")
@@ -0,0 +1,72 @@
+class MyString:
+    def __init__(self, s):
+        self.s = s
+
+    def __str__(self):
+        return self.s
+
+    def __add__(self, other):
+        kind = "i32" if isinstance(other, int) else "String"
+        print(f"> MyString.add<{kind}>({other}) was called")
+        return MyString(f"{self.s}{other}")
+
+    def __radd__(self, other):
+        print(f"> i32.add<MyString>({self}) was called")
+        return MyString(f"{other}{self.s}")
+
+    def __mul__(self, other):
+        print(f"> MyString.mul<i32>({other}) was called")
+        return MyString(self.s * other)
+
+
+class Matrix:
+    def __init__(self, rows):
+        self.rows = rows
+
+    @classmethod
+    def identity(cls, n):
+        return cls([[int(row == col) for col in range(n)] for row in range(n)])
+
+    def __add__(self, other):
+        return Matrix([[a + b for a, b in zip(r1, r2)] for r1, r2 in zip(self.rows, other.rows)])
+
+    def __mul__(self, other):
+        if isinstance(other, Matrix):
+            cols = list(zip(*other.rows))
+            return Matrix([[sum(a * b for a, b in zip(row, col)) for col in cols] for row in self.rows])
+        return Matrix([[a * other for a in row] for row in self.rows])
+
+    def transpose(self):
+        return Matrix([list(col) for col in zip(*self.rows)])
+
+    def determinant(self):
+        (a, b), (c, d) = self.rows
+        return a * d - b * c
+
+    def inverse(self):
+        (a, b), (c, d) = self.rows
+        det = self.determinant()
+        return Matrix([[d / det, -b / det], [-c / det, a / det]])
+
+    def __format__(self, spec):
+        return "\n".join("[" + ", ".join(format(x, spec) for x in row) + "]" for row in self.rows)
+
+
+print("---- Operator Overloading Start ----")
+ms = MyString("Foo")
+print(f"Foo + Bar = {ms + 'Bar'}")
+print(f"Foo2 + -12 = {ms + -12}")
+print(f"100 + Foo3 = {100 + ms}")
+print(f"Foo4 * -12 = {ms * -12}")
+print(f"Foo4 * 12 = {ms * 12}")
+
+a = Matrix([[1, 2], [3, 4]])
+print(f"a + I =\n{a + Matrix.identity(2)}")
+print(f"a * a =\n{a * a}")
+print(f"a * 3 =\n{a * 3}")
+print(f"a transposed =\n{a.transpose()}")
+print(f"det(a) = Ok({a.determinant()})")
+
+f = Matrix([[4.0, 7.0], [2.0, 6.0]])
+print(f"f inverted =\n{f.inverse():.2f}")
+print("---- Operator Overloading End ----")
@@ -0,0 +1,90 @@
+import asyncio
+import csv
+import errno
+from pathlib import Path
+
+# names of Rust's io::ErrorKind for the errors this lesson can hit
+ERROR_KINDS = {errno.ENOENT: "NotFound", errno.EISDIR: "IsADirectory", errno.EACCES: "PermissionDenied"}
+
+
+def number(x):
+    # Rust prints whole floats without the trailing .0
+    return int(x) if x == int(x) else x
+
+
+def read_sales(path):
+    with open(path, newline="") as f:
+        return [
+            {"Country": row["Country"], "Date": row["Date"], "Amount": float(row["Amount"])}
+            for row in csv.DictReader(f)
+        ]
+
+
+def read_sales_csv():
+    sales = read_sales("./data/sales.csv")
+
+    found = sum(1 for sale in sales if sale["Country"] == "Tanzania")
+    print(f"found {found}_sales for Tanzania")
+
+    keys = {
+        "Country": lambda sale: sale["Country"],
+        "Month": lambda sale: sale["Date"][:7],
+        "Year": lambda sale: sale["Date"][:4],
+    }
+    for by, key in keys.items():
+        print("Amount by", by)
+        groups = {}
+        for sale in sales:
+            groups.setdefault(key(sale), []).append(sale["Amount"])
+        for group, amounts in sorted(groups.items()):
+            print(
+                f"> {group}: count {len(amounts)} sum {number(sum(amounts))} "
+                f"mean {sum(amounts) / len(amounts):.2f} min {number(min(amounts))} max {number(max(amounts))}"
+            )
+
+    print("Amount histogram")
+    buckets = [("small", "[0, 50)", 0, 50), ("medium", "[50, 150)", 50, 150), ("large", "[150, inf)", 150, float("inf"))]
+    for label, bounds, low, high in buckets:
+        count = sum(1 for sale in sales if low <= sale["Amount"] < high)
+        print(f"{label:<6} {bounds:<10} {'#' * count} {count}")
+    return "Ok(())"
+
+
+def read_fonts():
+    data = Path("./data/sales.csv").read_bytes()
+    print(f"Data size {len(data)}bytes")
+
+    user_home = Path.home()
+    user_fonts = user_home / "Library" / "Fonts"
+    print(f'user_home: "{user_home}" user_fonts: "{user_fonts}"')
+
+    try:
+        user_fonts.read_bytes()
+    except OSError as e:
+        kind = ERROR_KINDS.get(e.errno, "Other")
+        return f'Err(Os {{ code: {e.errno}, kind: {kind}, message: "{e.strerror}" }})'
+    return "Ok(())"
+
+
+async def read_sales_files():
+    data = await asyncio.to_thread(Path("./data/sales_2023.csv").read_bytes)
+    print(f"Async data size {len(data)}bytes")
+
+    paths = ["./data/sales.csv", "./data/sales_2023.csv"]
+    results = await asyncio.gather(*(asyncio.to_thread(read_sales, path) for path in paths))
+    sales = [sale for result in results for sale in result]
+    print(f"merged {len(sales)} sales from {len(paths)} files")
+
+    groups = {}
+    for sale in sales:
+        groups.setdefault(sale["Country"], []).append(sale["Amount"])
+    for country, amounts in sorted(groups.items()):
+        print(f"> {country}: count {len(amounts)} sum {number(sum(amounts))}")
+    return "Ok(())"
+
+
+print("---- Files Start ----")
+print(read_sales_csv())
+print(read_fonts())
+print(asyncio.run(read_sales_files()))
+print("---- Files End ----")
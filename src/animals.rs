@@ -0,0 +1,491 @@
+// Animals to run the pattern matching examples over, classified with match guards
+// Python equivalent is `match animal: case Dog(age=0): ...` with dataclasses
+//
+// Animals can be read from data/animals.csv (tags separated by ';') or data/animals.json
+
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::Path;
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::csv::{self, CsvError};
+
+#[derive(Error, Debug)]
+pub enum AnimalError {
+    #[error(transparent)]
+    Csv(#[from] CsvError),
+    #[error("line {line}, column '{column}': {message}")]
+    Parse {
+        line: usize,
+        column: &'static str,
+        message: String,
+    },
+    #[error("could not read animals: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// A record `Animal::new` rejected, `field` is the column it's reported in
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{message}")]
+pub struct InvalidAnimal {
+    pub field: &'static str,
+    pub message: String,
+}
+
+/// What every animal has
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Details {
+    pub name: String,
+    /// in years
+    #[serde(default)]
+    pub age: u8,
+    /// in kg
+    #[serde(default)]
+    pub weight: f64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Details {
+    pub fn new(name: impl Into<String>, age: u8, weight: f64) -> Self {
+        Details {
+            name: name.into(),
+            age,
+            weight,
+            tags: Vec::new(),
+        }
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// In JSON the kind is a field, `{"kind": "bird", "species": "parrot", "name": "Polly"}`
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "JsonAnimal")]
+pub enum Animal {
+    Dog(Details),
+    Cat(Details),
+    Bird { species: String, details: Details },
+    Fish { species: String, details: Details },
+    Other { species: String, details: Details },
+}
+
+// goes through `Animal::new`, so JSON is checked the same way as CSV
+#[derive(Deserialize)]
+struct JsonAnimal {
+    kind: String,
+    #[serde(default)]
+    species: String,
+    #[serde(flatten)]
+    details: Details,
+}
+
+impl TryFrom<JsonAnimal> for Animal {
+    type Error = InvalidAnimal;
+
+    fn try_from(json: JsonAnimal) -> Result<Self, Self::Error> {
+        Animal::new(&json.kind, &json.species, json.details)
+    }
+}
+
+impl Animal {
+    /// `kind` is dog, cat, bird, fish or other, only the last three need a species.
+    /// Every animal needs a name and a weight that isn't negative
+    pub fn new(kind: &str, species: &str, details: Details) -> Result<Self, InvalidAnimal> {
+        let invalid = |field, message| Err(InvalidAnimal { field, message });
+        if details.name.trim().is_empty() {
+            return invalid("name", "an animal needs a name".to_string());
+        }
+        if !details.weight.is_finite() || details.weight < 0.0 {
+            let message = format!(
+                "weight must be a number that isn't negative, got {}",
+                details.weight
+            );
+            return invalid("weight", message);
+        }
+        let species = species.trim().to_string();
+        match (kind.trim().to_lowercase().as_str(), species.is_empty()) {
+            ("dog", _) => Ok(Animal::Dog(details)),
+            ("cat", _) => Ok(Animal::Cat(details)),
+            ("bird" | "fish" | "other", true) => {
+                invalid("kind", format!("a {} needs a species", kind))
+            }
+            ("bird", false) => Ok(Animal::Bird { species, details }),
+            ("fish", false) => Ok(Animal::Fish { species, details }),
+            ("other", false) => Ok(Animal::Other { species, details }),
+            (kind, _) => invalid(
+                "kind",
+                format!(
+                    "unknown kind '{}', expected dog, cat, bird, fish or other",
+                    kind
+                ),
+            ),
+        }
+    }
+
+    pub fn details(&self) -> &Details {
+        match self {
+            Animal::Dog(details) | Animal::Cat(details) => details,
+            Animal::Bird { details, .. }
+            | Animal::Fish { details, .. }
+            | Animal::Other { details, .. } => details,
+        }
+    }
+
+    pub fn species(&self) -> &str {
+        match self {
+            Animal::Dog(_) => "dog",
+            Animal::Cat(_) => "cat",
+            Animal::Bird { species, .. }
+            | Animal::Fish { species, .. }
+            | Animal::Other { species, .. } => species,
+        }
+    }
+}
+
+/// Result of classifying an animal, `label` is the category and `text` reads as a sentence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    pub label: String,
+    pub text: String,
+}
+
+impl fmt::Display for Description {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// Built-in rules, the first arm that matches wins so the specific ones come first
+pub fn classify(animal: &Animal) -> Description {
+    let (label, text) = match animal {
+        Animal::Dog(Details { name, age: 0, .. }) => ("puppy", format!("A puppy named: {}", name)),
+        Animal::Dog(d) if d.has_tag("guide") => {
+            ("guide dog", format!("A guide dog named: {}", d.name))
+        }
+        // name patterns
+        Animal::Dog(Details { name, .. })
+            if name.starts_with("Sir ") || name.starts_with("Lady ") =>
+        {
+            ("noble dog", format!("A dog of noble birth: {}", name))
+        }
+        Animal::Dog(Details {
+            name, age: 10.., ..
+        }) => ("senior dog", format!("A senior dog named: {}", name)),
+        Animal::Dog(d) => ("dog", format!("A dog named: {}", d.name)),
+        Animal::Cat(Details { name, age: 0, .. }) => {
+            ("kitten", format!("A kitten named: {}", name))
+        }
+        Animal::Cat(Details { name, weight, .. }) if *weight > 6.0 => (
+            "big cat",
+            format!("A big cat named: {}, {:.1} kg", name, weight),
+        ),
+        Animal::Cat(Details { age, .. }) => ("cat", format!("A cat aged: {}", age)),
+        Animal::Bird { species, details } if details.has_tag("talks") => (
+            "talking bird",
+            format!("A {} that talks, named: {}", species, details.name),
+        ),
+        Animal::Bird { species, details } => {
+            ("bird", format!("A {} named: {}", species, details.name))
+        }
+        Animal::Fish {
+            species,
+            details: Details { weight, .. },
+        } if *weight >= 100.0 => ("big fish", format!("A {} of {:.0} kg", species, weight)),
+        Animal::Fish { species, details } => {
+            ("fish", format!("A {} named: {}", species, details.name))
+        }
+        Animal::Other { species, details } => (
+            "other",
+            format!(
+                "A {} aged: {}, named: {}",
+                species, details.age, details.name
+            ),
+        ),
+        // _ => ("any animal", ...) <- every variant is covered, here will show a warning
+    };
+    Description {
+        label: label.to_string(),
+        text,
+    }
+}
+
+struct Rule {
+    label: String,
+    matches: Box<dyn Fn(&Animal) -> bool>,
+}
+
+/// Custom rules checked in order before the built-in ones
+///
+/// let classifier = Classifier::new().rule("heavy", |animal| animal.details().weight > 50.0);
+#[derive(Default)]
+pub struct Classifier {
+    rules: Vec<Rule>,
+}
+
+impl Classifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn rule(
+        mut self,
+        label: impl Into<String>,
+        matches: impl Fn(&Animal) -> bool + 'static,
+    ) -> Self {
+        self.rules.push(Rule {
+            label: label.into(),
+            matches: Box::new(matches),
+        });
+        self
+    }
+
+    pub fn classify(&self, animal: &Animal) -> Description {
+        match self.rules.iter().find(|rule| (rule.matches)(animal)) {
+            Some(rule) => Description {
+                label: rule.label.clone(),
+                text: format!(
+                    "A {} {} named: {}",
+                    rule.label,
+                    animal.species(),
+                    animal.details().name
+                ),
+            },
+            None => classify(animal),
+        }
+    }
+}
+
+pub fn read_csv(path: impl AsRef<Path>) -> Result<Vec<Animal>, AnimalError> {
+    animals_from(csv::Reader::from_path(path)?)
+}
+
+pub fn parse_csv(reader: impl BufRead) -> Result<Vec<Animal>, AnimalError> {
+    animals_from(csv::Reader::new(reader)?)
+}
+
+/// A list of animals, `[{"kind": "dog", "name": "Fido", "age": 3}]`
+pub fn read_json(path: impl AsRef<Path>) -> Result<Vec<Animal>, AnimalError> {
+    parse_json(&fs::read_to_string(path)?)
+}
+
+pub fn parse_json(json: &str) -> Result<Vec<Animal>, AnimalError> {
+    Ok(serde_json::from_str(json)?)
+}
+
+fn animals_from<R: BufRead>(reader: csv::Reader<R>) -> Result<Vec<Animal>, AnimalError> {
+    let columns = Columns::new(reader.headers())?;
+    reader.map(|record| columns.animal(&record?)).collect()
+}
+
+// kind and name are required, the other columns can be left out
+struct Columns {
+    kind: usize,
+    name: usize,
+    species: Option<usize>,
+    age: Option<usize>,
+    weight: Option<usize>,
+    tags: Option<usize>,
+}
+
+impl Columns {
+    fn new(headers: &csv::Headers) -> Result<Self, AnimalError> {
+        Ok(Self {
+            kind: headers.require("kind")?,
+            name: headers.require("name")?,
+            species: headers.index("species"),
+            age: headers.index("age"),
+            weight: headers.index("weight"),
+            tags: headers.index("tags"),
+        })
+    }
+
+    fn animal(&self, record: &csv::Record) -> Result<Animal, AnimalError> {
+        let field = |index: Option<usize>| {
+            index
+                .and_then(|index| record.get(index))
+                .unwrap_or_default()
+                .trim()
+        };
+        let parse_error = |column, message| AnimalError::Parse {
+            line: record.line(),
+            column,
+            message,
+        };
+
+        let (age, weight) = (field(self.age), field(self.weight));
+        let details = Details {
+            name: field(Some(self.name)).to_string(),
+            age: match age {
+                "" => 0,
+                _ => age
+                    .parse()
+                    .map_err(|e| parse_error("age", format!("invalid age '{}': {}", age, e)))?,
+            },
+            weight: match weight {
+                "" => 0.0,
+                _ => weight.parse().map_err(|e| {
+                    parse_error("weight", format!("invalid weight '{}': {}", weight, e))
+                })?,
+            },
+            tags: field(self.tags)
+                .split(';')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+        };
+        Animal::new(field(Some(self.kind)), field(self.species), details)
+            .map_err(|e| parse_error(e.field, e.message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn labels(animals: &[Animal]) -> Vec<String> {
+        animals.iter().map(|a| classify(a).label).collect()
+    }
+
+    #[test]
+    fn classifies_with_guards() {
+        let dog = |name: &str, age| Animal::Dog(Details::new(name, age, 10.0));
+        assert_eq!(classify(&dog("Fido", 3)).text, "A dog named: Fido");
+        assert_eq!(classify(&dog("Rex", 0)).label, "puppy");
+        assert_eq!(classify(&dog("Max", 12)).label, "senior dog");
+        // name pattern is checked before the age
+        assert_eq!(classify(&dog("Sir Max", 12)).label, "noble dog");
+        let guide = Animal::Dog(Details::new("Buddy", 0, 30.0).tag("guide"));
+        assert_eq!(classify(&guide).label, "puppy");
+
+        let cat = |age, weight| Animal::Cat(Details::new("Tom", age, weight));
+        assert_eq!(classify(&cat(3, 4.0)).text, "A cat aged: 3");
+        assert_eq!(classify(&cat(8, 7.46)).text, "A big cat named: Tom, 7.5 kg");
+        assert_eq!(classify(&cat(0, 7.0)).label, "kitten");
+    }
+
+    #[test]
+    fn custom_rules_come_first() {
+        let classifier = Classifier::new().rule("heavy", |a| a.details().weight > 50.0);
+        let shark = Animal::Fish {
+            species: "shark".to_string(),
+            details: Details::new("Jaws", 25, 1100.0),
+        };
+        assert_eq!(
+            classifier.classify(&shark).text,
+            "A heavy shark named: Jaws"
+        );
+        assert_eq!(classify(&shark).text, "A shark of 1100 kg");
+        let cat = Animal::Cat(Details::new("Tom", 3, 4.0));
+        assert_eq!(classifier.classify(&cat), classify(&cat));
+    }
+
+    #[test]
+    fn parses_csv() {
+        let animals = parse_csv(
+            "kind,name,species,age,weight,tags
+dog,Buddy,,7,30,guide; calm
+bird,Polly,parrot,30,0.4,talks
+cat,Tom,,,,"
+                .as_bytes(),
+        )
+        .unwrap();
+        assert_eq!(
+            labels(&animals),
+            vec!["guide dog", "talking bird", "kitten"]
+        );
+        assert_eq!(animals[0].details().tags, vec!["guide", "calm"]);
+        assert_eq!(animals[1].species(), "parrot");
+        assert_eq!(animals[2].details().age, 0);
+
+        let error = parse_csv("kind,name,age\ndog,Rex,old".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 'age': invalid age 'old': invalid digit found in string"
+        );
+        let error = parse_csv("kind,name,species\nfish,Nemo,".as_bytes()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 'kind': a fish needs a species"
+        );
+        for (csv, expected) in [
+            ("kind,name\ndog, ", "column 'name': an animal needs a name"),
+            (
+                "kind,name,weight\ndog,Rex,-1",
+                "column 'weight': weight must be a number that isn't negative, got -1",
+            ),
+            (
+                "kind,name,weight\ndog,Rex,NaN",
+                "column 'weight': weight must be a number that isn't negative, got NaN",
+            ),
+            (
+                "kind,name,weight\ndog,Rex,inf",
+                "column 'weight': weight must be a number that isn't negative, got inf",
+            ),
+        ] {
+            let error = parse_csv(csv.as_bytes()).unwrap_err().to_string();
+            assert_eq!(error, format!("line 2, {}", expected));
+        }
+        assert!(matches!(
+            parse_csv("name\nRex".as_bytes()),
+            Err(AnimalError::Csv(CsvError::MissingColumn(_)))
+        ));
+    }
+
+    #[test]
+    fn parses_json() {
+        let animals = parse_json(
+            r#"[
+                {"kind": "cat", "name": "Garfield", "age": 8, "weight": 7.5, "tags": ["lazy"]},
+                {"kind": "other", "species": "tortoise", "name": "Shelly", "age": 80}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(labels(&animals), vec!["big cat", "other"]);
+        assert_eq!(animals[1].details().weight, 0.0);
+        assert!(matches!(
+            parse_json(r#"[{"kind": "dragon", "name": "Smaug"}]"#),
+            Err(AnimalError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn validates_json_like_csv() {
+        let animals = parse_json(r#"[{"kind": "Bird", "species": " parrot ", "name": "Polly"}]"#);
+        assert_eq!(animals.unwrap()[0].species(), "parrot");
+        assert!(parse_json(r#"[{"kind": "DOG", "name": "Rex"}]"#).is_ok());
+
+        for kind in ["bird", "fish", "other"] {
+            let json = format!(r#"[{{"kind": "{}", "species": " ", "name": "x"}}]"#, kind);
+            let error = parse_json(&json).unwrap_err().to_string();
+            assert!(
+                error.contains(&format!("a {} needs a species", kind)),
+                "{}",
+                error
+            );
+        }
+        assert!(parse_json(r#"[{"kind": "fish", "name": "Nemo"}]"#).is_err());
+        assert!(parse_json(r#"[{"kind": "dog", "name": " "}]"#).is_err());
+        assert!(parse_json(r#"[{"kind": "dog", "name": "Rex", "weight": -1}]"#).is_err());
+    }
+
+    #[test]
+    fn reads_data_files() {
+        let from_csv = read_csv("./data/animals.csv").unwrap();
+        let from_json = read_json("./data/animals.json").unwrap();
+        assert_eq!(from_csv, from_json);
+    }
+}
@@ -0,0 +1,443 @@
+// Labelled ranges to bin numbers into, what `match x { 1 => .., 2 | 3 => .., 4.. => .. }` does
+// but defined at runtime and for floats too
+//
+// let sizes = Buckets::builder()
+//     .bucket("small", ..50.0)
+//     .bucket("medium", 50.0..150.0)
+//     .bucket("large", 150.0..)
+//     .build()?;
+// sizes.classify(100.0) -> Some("medium")
+//
+// `build` fails when two buckets overlap or leave a gap between them, values below the first
+// or above the last bucket are not classified.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum BucketError {
+    #[error("bucket '{0}' is empty")]
+    Empty(String),
+    #[error("bucket '{0}' has a NaN bound")]
+    NaN(String),
+    #[error("buckets '{0}' and '{1}' overlap")]
+    Overlap(String, String),
+    #[error("values between buckets '{0}' and '{1}' are not covered")]
+    Gap(String, String),
+}
+
+/// Numbers that can be bucketed, integers are discrete so `..=3` and `4..` leave no gap
+pub trait Value: Copy + PartialOrd + fmt::Display {
+    const DISCRETE: bool;
+    /// The next value up, None for floats and at the maximum
+    fn succ(self) -> Option<Self>;
+    fn pred(self) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Value for $t {
+            const DISCRETE: bool = true;
+
+            fn succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn pred(self) -> Option<Self> {
+                self.checked_sub(1)
+            }
+        })*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(impl Value for $t {
+            const DISCRETE: bool = false;
+
+            fn succ(self) -> Option<Self> {
+                None
+            }
+
+            fn pred(self) -> Option<Self> {
+                None
+            }
+        })*
+    };
+}
+
+impl_float!(f32, f64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bucket<T> {
+    pub label: String,
+    pub start: Bound<T>,
+    pub end: Bound<T>,
+}
+
+impl<T: Value> Bucket<T> {
+    pub fn contains(&self, value: T) -> bool {
+        (self.start, self.end).contains(&value)
+    }
+
+    fn is_empty(&self) -> bool {
+        match (self.start, self.end) {
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Included(end)) => start >= end,
+            _ => false,
+        }
+    }
+
+    fn has_nan(&self) -> bool {
+        [self.start, self.end].iter().any(|bound| match bound {
+            #[allow(clippy::eq_op)] // NaN is the only value not equal to itself
+            Bound::Included(x) | Bound::Excluded(x) => x != x,
+            Bound::Unbounded => false,
+        })
+    }
+
+    // integer bounds are made inclusive, 1..4 becomes 1..=3, None if nothing is left
+    fn normalized(mut self) -> Option<Self> {
+        if !T::DISCRETE {
+            return Some(self);
+        }
+        if let Bound::Excluded(start) = self.start {
+            self.start = Bound::Included(start.succ()?);
+        }
+        if let Bound::Excluded(end) = self.end {
+            self.end = Bound::Included(end.pred()?);
+        }
+        Some(self)
+    }
+}
+
+/// `[1, 3]`, `(-inf, 50)`
+impl<T: fmt::Display> fmt::Display for Bucket<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.start {
+            Bound::Included(start) => write!(f, "[{}, ", start)?,
+            Bound::Excluded(start) => write!(f, "({}, ", start)?,
+            Bound::Unbounded => write!(f, "(-inf, ")?,
+        }
+        match &self.end {
+            Bound::Included(end) => write!(f, "{}]", end),
+            Bound::Excluded(end) => write!(f, "{})", end),
+            Bound::Unbounded => write!(f, "inf)"),
+        }
+    }
+}
+
+pub struct BucketsBuilder<T> {
+    buckets: Vec<Bucket<T>>,
+}
+
+impl<T: Value> BucketsBuilder<T> {
+    /// `1..4`, `1..=3`, `..0` and `4..` all work
+    pub fn bucket(mut self, label: impl Into<String>, range: impl RangeBounds<T>) -> Self {
+        self.buckets.push(Bucket {
+            label: label.into(),
+            start: range.start_bound().cloned(),
+            end: range.end_bound().cloned(),
+        });
+        self
+    }
+
+    /// Sorts the buckets and checks there are no overlaps or gaps between them
+    pub fn build(self) -> Result<Buckets<T>, BucketError> {
+        let mut buckets = Vec::with_capacity(self.buckets.len());
+        for bucket in self.buckets {
+            if bucket.has_nan() {
+                return Err(BucketError::NaN(bucket.label));
+            }
+            let label = bucket.label.clone();
+            match bucket.normalized() {
+                Some(bucket) if !bucket.is_empty() => buckets.push(bucket),
+                _ => return Err(BucketError::Empty(label)),
+            }
+        }
+        buckets.sort_by(|a, b| compare_starts(a.start, b.start));
+
+        for pair in buckets.windows(2) {
+            let (first, second) = (&pair[0], &pair[1]);
+            let names = || (first.label.clone(), second.label.clone());
+            match between(first.end, second.start) {
+                Between::Overlap => {
+                    let (a, b) = names();
+                    return Err(BucketError::Overlap(a, b));
+                }
+                Between::Gap => {
+                    let (a, b) = names();
+                    return Err(BucketError::Gap(a, b));
+                }
+                Between::Adjacent => {}
+            }
+        }
+        Ok(Buckets { buckets })
+    }
+}
+
+fn compare_starts<T: Value>(a: Bound<T>, b: Bound<T>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(a) | Bound::Excluded(a), Bound::Included(b) | Bound::Excluded(b))
+            if a != b =>
+        {
+            a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+        }
+        // [1 starts before (1
+        (Bound::Included(_), Bound::Excluded(_)) => Ordering::Less,
+        (Bound::Excluded(_), Bound::Included(_)) => Ordering::Greater,
+        _ => Ordering::Equal,
+    }
+}
+
+enum Between {
+    Overlap,
+    Gap,
+    Adjacent,
+}
+
+// how the end of one bucket meets the start of the next one, the buckets are sorted by start
+fn between<T: Value>(end: Bound<T>, start: Bound<T>) -> Between {
+    let (end_value, start_value) = match (end, start) {
+        (Bound::Included(e) | Bound::Excluded(e), Bound::Included(s) | Bound::Excluded(s)) => {
+            (e, s)
+        }
+        // one of them goes on forever
+        _ => return Between::Overlap,
+    };
+    if end_value > start_value {
+        return Between::Overlap;
+    }
+    if end_value == start_value {
+        // the value where they meet has to belong to exactly one of them
+        return match (end, start) {
+            (Bound::Included(_), Bound::Included(_)) => Between::Overlap,
+            (Bound::Excluded(_), Bound::Excluded(_)) => Between::Gap,
+            _ => Between::Adjacent,
+        };
+    }
+    // integer ranges are inclusive by now, ..=3 and 4.. meet
+    match T::DISCRETE && end_value.succ() == Some(start_value) {
+        true => Between::Adjacent,
+        false => Between::Gap,
+    }
+}
+
+pub struct Buckets<T> {
+    buckets: Vec<Bucket<T>>,
+}
+
+impl<T: Value> Buckets<T> {
+    pub fn builder() -> BucketsBuilder<T> {
+        BucketsBuilder {
+            buckets: Vec::new(),
+        }
+    }
+
+    /// Sorted from the lowest to the highest
+    pub fn buckets(&self) -> &[Bucket<T>] {
+        &self.buckets
+    }
+
+    /// Label of the bucket `value` falls into
+    pub fn classify(&self, value: T) -> Option<&str> {
+        self.buckets
+            .iter()
+            .find(|bucket| bucket.contains(value))
+            .map(|bucket| bucket.label.as_str())
+    }
+
+    pub fn classify_all(&self, values: impl IntoIterator<Item = T>) -> Vec<Option<&str>> {
+        values
+            .into_iter()
+            .map(|value| self.classify(value))
+            .collect()
+    }
+
+    /// How many values fall into every bucket
+    pub fn histogram(&self, values: impl IntoIterator<Item = T>) -> Histogram {
+        let mut counts = vec![0; self.buckets.len()];
+        let mut uncovered = 0;
+        for value in values {
+            match self
+                .buckets
+                .iter()
+                .position(|bucket| bucket.contains(value))
+            {
+                Some(i) => counts[i] += 1,
+                None => uncovered += 1,
+            }
+        }
+        Histogram {
+            counts: self
+                .buckets
+                .iter()
+                .map(|bucket| (bucket.label.clone(), bucket.to_string()))
+                .zip(counts)
+                .map(|((label, range), count)| (label, range, count))
+                .collect(),
+            uncovered,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Histogram {
+    /// label, range and count of every bucket, lowest first
+    pub counts: Vec<(String, String, usize)>,
+    /// values outside of every bucket
+    pub uncovered: usize,
+}
+
+impl fmt::Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .counts
+            .iter()
+            .map(|(label, _, _)| label.chars().count())
+            .max();
+        let range_width = self.counts.iter().map(|(_, range, _)| range.len()).max();
+        let (width, range_width) = (width.unwrap_or(0), range_width.unwrap_or(0));
+        for (i, (label, range, count)) in self.counts.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{:<width$} {:<range_width$} {} {}",
+                label,
+                range,
+                "#".repeat(*count),
+                count
+            )?;
+        }
+        if self.uncovered > 0 {
+            write!(f, "\nuncovered {}", self.uncovered)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_like_match() {
+        let numbers = Buckets::builder()
+            .bucket("one", 1..=1)
+            .bucket("two or three", 2..4)
+            .bucket("four or bigger", 4..)
+            .bucket("anything", ..1)
+            .build()
+            .unwrap();
+        assert_eq!(
+            numbers.classify_all(0..6),
+            vec![
+                Some("anything"),
+                Some("one"),
+                Some("two or three"),
+                Some("two or three"),
+                Some("four or bigger"),
+                Some("four or bigger"),
+            ]
+        );
+        assert_eq!(numbers.classify(i32::MIN), Some("anything"));
+        let labels: Vec<&str> = numbers.buckets().iter().map(|b| b.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            vec!["anything", "one", "two or three", "four or bigger"]
+        );
+        assert_eq!(numbers.buckets()[2].to_string(), "[2, 3]");
+    }
+
+    #[test]
+    fn floats_need_matching_bounds() {
+        let sizes = Buckets::builder()
+            .bucket("small", 0.0..50.0)
+            .bucket("medium", 50.0..=150.0)
+            .bucket("large", (Bound::Excluded(150.0), Bound::Unbounded))
+            .build()
+            .unwrap();
+        assert_eq!(sizes.classify(50.0), Some("medium"));
+        assert_eq!(sizes.classify(150.0), Some("medium"));
+        assert_eq!(sizes.classify(150.5), Some("large"));
+        assert_eq!(sizes.classify(-0.1), None);
+        assert_eq!(sizes.buckets()[2].to_string(), "(150, inf)");
+
+        let gap = Buckets::builder()
+            .bucket("small", ..=3.0)
+            .bucket("large", 4.0..)
+            .build();
+        assert_eq!(
+            gap.err(),
+            Some(BucketError::Gap("small".into(), "large".into()))
+        );
+        let excluded_twice = Buckets::builder()
+            .bucket("small", ..3.0)
+            .bucket("large", (Bound::Excluded(3.0), Bound::Unbounded))
+            .build();
+        assert!(matches!(excluded_twice, Err(BucketError::Gap(_, _))));
+    }
+
+    #[test]
+    fn rejects_bad_buckets() {
+        let overlap = Buckets::builder()
+            .bucket("a", 1..=5)
+            .bucket("b", 5..10)
+            .build();
+        assert_eq!(
+            overlap.err(),
+            Some(BucketError::Overlap("a".into(), "b".into()))
+        );
+        let gap = Buckets::builder()
+            .bucket("a", 1..5)
+            .bucket("b", 6..10)
+            .build();
+        assert_eq!(gap.err(), Some(BucketError::Gap("a".into(), "b".into())));
+        let unbounded = Buckets::builder()
+            .bucket("a", 1..)
+            .bucket("b", 6..10)
+            .build();
+        assert!(matches!(unbounded, Err(BucketError::Overlap(_, _))));
+
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = Buckets::builder().bucket("a", 5..5).build();
+        assert_eq!(empty.err(), Some(BucketError::Empty("a".into())));
+        let empty = Buckets::builder().bucket("min", ..i32::MIN).build();
+        assert_eq!(empty.err(), Some(BucketError::Empty("min".into())));
+        let nan = Buckets::builder().bucket("nan", f64::NAN..1.0).build();
+        assert_eq!(nan.err(), Some(BucketError::NaN("nan".into())));
+    }
+
+    #[test]
+    fn draws_histograms() {
+        let sizes = Buckets::builder()
+            .bucket("small", 0..10)
+            .bucket("large", 10..100)
+            .build()
+            .unwrap();
+        let histogram = sizes.histogram([1, 5, 50, 500, 7]);
+        assert_eq!(
+            histogram.counts,
+            vec![
+                ("small".to_string(), "[0, 9]".to_string(), 3),
+                ("large".to_string(), "[10, 99]".to_string(), 1),
+            ]
+        );
+        assert_eq!(histogram.uncovered, 1);
+        assert_eq!(
+            histogram.to_string(),
+            "small [0, 9]   ### 3\nlarge [10, 99] # 1\nuncovered 1"
+        );
+    }
+}
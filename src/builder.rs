@@ -0,0 +1,109 @@
+// Typestate builders, required fields are checked by the compiler and the rest by `build()`
+// Python would raise TypeError for a missing argument at runtime, here `build` doesn't exist
+// until every required field is set:
+//
+// struct DBuilder<X> { x: X, y: i32 }
+// impl DBuilder<Missing> { fn x(self, x: i32) -> DBuilder<Set<i32>> }
+// impl DBuilder<Set<i32>> { fn build(self) -> Result<D, ValidationError> }
+
+use thiserror::Error;
+
+/// A required field that hasn't been set yet
+///
+/// ```compile_fail
+/// use python2rust::builder::{Missing, Set};
+///
+/// struct Builder<X> { x: X }
+/// impl Builder<Set<i32>> {
+///     fn build(self) -> i32 { self.x.0 }
+/// }
+///
+/// Builder { x: Missing }.build(); // x was never set
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Missing;
+
+/// A required field with its value
+#[derive(Debug, Clone, Copy)]
+pub struct Set<T>(pub T);
+
+/// Every rule a value broke, not only the first one
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("invalid {name}: {}", .violations.join("; "))]
+pub struct ValidationError {
+    pub name: &'static str,
+    pub violations: Vec<String>,
+}
+
+/// Runs every rule, then fails with all the violations at once
+pub struct Validator<T> {
+    value: T,
+    error: ValidationError,
+}
+
+impl<T> Validator<T> {
+    pub fn new(name: &'static str, value: T) -> Self {
+        Validator {
+            value,
+            error: ValidationError {
+                name,
+                violations: Vec::new(),
+            },
+        }
+    }
+
+    /// `message` is recorded when `ok` returns false
+    pub fn rule(mut self, ok: impl FnOnce(&T) -> bool, message: impl FnOnce(&T) -> String) -> Self {
+        if !ok(&self.value) {
+            self.error.violations.push(message(&self.value));
+        }
+        self
+    }
+
+    pub fn finish(self) -> Result<T, ValidationError> {
+        match self.error.violations.is_empty() {
+            true => Ok(self.value),
+            false => Err(self.error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // the builders the lessons use, in the traits lesson x is required and y defaults to 0
+    use crate::{v07, D};
+
+    #[test]
+    fn builds_valid_values() {
+        assert_eq!(D::builder().y(2).x(1).build(), Ok(D { x: 1, y: 2 }));
+        assert_eq!(D::builder().x(1).build(), Ok(D { x: 1, y: 0 }));
+    }
+
+    #[test]
+    fn lists_every_violation() {
+        let error = D::builder().x(i32::MAX).y(i32::MIN).build().unwrap_err();
+        assert_eq!(error.name, "D");
+        assert_eq!(error.violations.len(), 2);
+        assert_eq!(
+            error.to_string(),
+            "invalid D: x must be within ±1073741823, got 2147483647; \
+             y must be within ±1073741823, got -2147483648"
+        );
+    }
+
+    #[test]
+    fn lists_every_violation_of_the_structs_lesson() {
+        let d = v07::D::builder().x(1).y(2).z(3).build().unwrap();
+        assert_eq!((d.x, d.y, d.z), (1, 2, 3));
+
+        let error = v07::D::builder().x(-1).y(-2).z(300).build().unwrap_err();
+        assert_eq!(
+            error.violations,
+            vec![
+                "x must not be negative, got -1",
+                "y must not be negative, got -2",
+                "z must be at most 100, got 300",
+            ]
+        );
+    }
+}
@@ -0,0 +1,246 @@
+// Case conversion with Python's semantics
+// `naive_capitalize` uppercases the first char, which turns "ßa" into "SSa" and "ǆungla" into
+// "Ǆungla", Python's `str.capitalize()` gives "Ssa" and "ǅungla" because it uses titlecase.
+
+/// Titlecase of a single char, same as Python's `c.title()`
+/// Rust's std only has upper and lower case, titlecase differs for digraphs, ligatures
+/// and Greek letters with ypogegrammeni (from Unicode's UnicodeData.txt and SpecialCasing.txt)
+pub fn titlecase(c: char) -> String {
+    let special = match c {
+        'Ǆ' | 'ǅ' | 'ǆ' => "ǅ",
+        'Ǉ' | 'ǈ' | 'ǉ' => "ǈ",
+        'Ǌ' | 'ǋ' | 'ǌ' => "ǋ",
+        'Ǳ' | 'ǲ' | 'ǳ' => "ǲ",
+        'ß' => "Ss",
+        'ﬀ' => "Ff",
+        'ﬁ' => "Fi",
+        'ﬂ' => "Fl",
+        'ﬃ' => "Ffi",
+        'ﬄ' => "Ffl",
+        'ﬅ' | 'ﬆ' => "St",
+        'և' => "Եւ",
+        'ﬓ' => "Մն",
+        'ﬔ' => "Մե",
+        'ﬕ' => "Մի",
+        'ﬖ' => "Վն",
+        'ﬗ' => "Մխ",
+        'ᾳ' | 'ᾼ' => "ᾼ",
+        'ῃ' | 'ῌ' => "ῌ",
+        'ῳ' | 'ῼ' => "ῼ",
+        'ᾲ' => "Ὰ\u{345}",
+        'ᾴ' => "Ά\u{345}",
+        'ᾷ' => "Α\u{342}\u{345}",
+        'ῂ' => "Ὴ\u{345}",
+        'ῄ' => "Ή\u{345}",
+        'ῇ' => "Η\u{342}\u{345}",
+        'ῲ' => "Ὼ\u{345}",
+        'ῴ' => "Ώ\u{345}",
+        'ῷ' => "Ω\u{342}\u{345}",
+        _ => "",
+    };
+    if !special.is_empty() {
+        return special.to_string();
+    }
+
+    match c {
+        // ᾀ -> ᾈ, the uppercase would be two chars, "ἈΙ"
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            char::from_u32(c as u32 + 8).map_or_else(String::new, String::from)
+        }
+        // already titlecase
+        '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}' => {
+            c.to_string()
+        }
+        // Georgian Mkhedruli has an uppercase (Mtavruli) but no titlecase
+        '\u{10D0}'..='\u{10FA}' | '\u{10FD}'..='\u{10FF}' => c.to_string(),
+        _ => c.to_uppercase().collect(),
+    }
+}
+
+// Lowercase, Uppercase or Titlecase letter
+fn is_cased(c: char) -> bool {
+    c.is_lowercase()
+        || c.is_uppercase()
+        || matches!(c, 'ǅ' | 'ǈ' | 'ǋ' | 'ǲ' | 'ᾼ' | 'ῌ' | 'ῼ')
+        || matches!(c, '\u{1F88}'..='\u{1F8F}' | '\u{1F98}'..='\u{1F9F}' | '\u{1FA8}'..='\u{1FAF}')
+}
+
+// Python lowercases with the whole string as context for the final sigma, so the lowercase
+// of every char is sliced out of `s.to_lowercase()`. ς and σ have the same length, so the
+// byte offsets line up.
+fn map_chars(s: &str, mut push: impl FnMut(&mut String, char, &str)) -> String {
+    let lower = s.to_lowercase();
+    let mut out = String::with_capacity(s.len());
+    let mut offset = 0;
+    for c in s.chars() {
+        let len: usize = c.to_lowercase().map(char::len_utf8).sum();
+        push(&mut out, c, &lower[offset..offset + len]);
+        offset += len;
+    }
+    out
+}
+
+/// Python's `str.capitalize()`: first char titlecased, the rest lowercased
+pub fn capitalize(s: &str) -> String {
+    let mut first = true;
+    map_chars(s, |out, c, lower| {
+        if first {
+            out.push_str(&titlecase(c));
+            first = false;
+        } else {
+            out.push_str(lower);
+        }
+    })
+}
+
+/// Python's `str.title()`: titlecase after every uncased char, so "they're" becomes "They'Re"
+pub fn title(s: &str) -> String {
+    let mut previous_is_cased = false;
+    map_chars(s, |out, c, lower| {
+        if previous_is_cased {
+            out.push_str(lower);
+        } else {
+            out.push_str(&titlecase(c));
+        }
+        previous_is_cased = is_cased(c);
+    })
+}
+
+/// Python's `string.capwords()`: capitalizes whitespace separated words, "they're" stays "They're"
+pub fn capwords(s: &str) -> String {
+    s.split_whitespace()
+        .map(capitalize)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits identifiers and phrases into words, on separators and case changes
+/// "HTTPServer error_code" -> ["HTTP", "Server", "error", "code"]
+pub fn words(s: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut start: Option<usize> = None;
+
+    for (i, &(pos, c)) in chars.iter().enumerate() {
+        if !c.is_alphanumeric() {
+            if let Some(begin) = start.take() {
+                words.push(&s[begin..pos]);
+            }
+            continue;
+        }
+
+        let Some(begin) = start else {
+            start = Some(pos);
+            continue;
+        };
+        let prev = chars[i - 1].1;
+        let next = chars.get(i + 1).map(|&(_, c)| c);
+        // fooBar, or the last capital of an acronym in HTTPServer
+        let boundary = c.is_uppercase()
+            && (prev.is_lowercase()
+                || prev.is_numeric()
+                || (prev.is_uppercase() && next.is_some_and(char::is_lowercase)));
+        if boundary {
+            words.push(&s[begin..pos]);
+            start = Some(pos);
+        }
+    }
+    if let Some(begin) = start {
+        words.push(&s[begin..]);
+    }
+    words
+}
+
+pub fn to_snake_case(s: &str) -> String {
+    join_lowercase(s, "_")
+}
+
+pub fn to_kebab_case(s: &str) -> String {
+    join_lowercase(s, "-")
+}
+
+pub fn to_pascal_case(s: &str) -> String {
+    words(s).into_iter().map(capitalize).collect()
+}
+
+pub fn to_camel_case(s: &str) -> String {
+    words(s)
+        .into_iter()
+        .enumerate()
+        .map(|(i, word)| match i {
+            0 => word.to_lowercase(),
+            _ => capitalize(word),
+        })
+        .collect()
+}
+
+fn join_lowercase(s: &str, separator: &str) -> String {
+    words(s)
+        .into_iter()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // expected values are from Python 3.11
+    #[test]
+    fn capitalize_like_python() {
+        assert_eq!(capitalize("óleg"), "Óleg");
+        assert_eq!(capitalize("¶πø^ä"), "¶πø^ä");
+        assert_eq!(capitalize("ǆungla"), "ǅungla");
+        assert_eq!(capitalize("ßa"), "Ssa");
+        assert_eq!(capitalize("ﬁre"), "Fire");
+        assert_eq!(capitalize("ΑΣ ΟΔΟΣ."), "Ας οδος.");
+        assert_eq!(capitalize("ǉubljana IS ǈUBLJANA"), "ǈubljana is ǉubljana");
+        assert_eq!(capitalize("ΣΑΣ"), "Σας");
+        assert_eq!(capitalize(""), "");
+    }
+
+    #[test]
+    fn title_like_python() {
+        assert_eq!(title("¶πø^ä"), "¶Πø^Ä");
+        assert_eq!(
+            title("they're bill's friends from the UK"),
+            "They'Re Bill'S Friends From The Uk"
+        );
+        assert_eq!(title("ǉubljana IS ǈUBLJANA"), "ǈubljana Is ǈubljana");
+        assert_eq!(title("hello world2go"), "Hello World2Go");
+        assert_eq!(title("ᾳ ᾷ"), "ᾼ Α\u{342}\u{345}");
+        assert_eq!(title("ŉa"), "ʼNa");
+        assert_eq!(title("ἀΣ'"), "Ἀς'");
+        assert_eq!(title("ა"), "ა");
+    }
+
+    #[test]
+    fn capwords_like_python() {
+        assert_eq!(
+            capwords("they're  bill's friends"),
+            "They're Bill's Friends"
+        );
+    }
+
+    #[test]
+    fn splits_words() {
+        assert_eq!(
+            words("HTTPServer error_code"),
+            vec!["HTTP", "Server", "error", "code"]
+        );
+        assert_eq!(words("fooBar2Baz"), vec!["foo", "Bar2", "Baz"]);
+        assert_eq!(words("--óleg--Über--"), vec!["óleg", "Über"]);
+    }
+
+    #[test]
+    fn converts_case() {
+        let s = "parse HTTPResponse code";
+        assert_eq!(to_snake_case(s), "parse_http_response_code");
+        assert_eq!(to_kebab_case(s), "parse-http-response-code");
+        assert_eq!(to_camel_case(s), "parseHttpResponseCode");
+        assert_eq!(to_pascal_case(s), "ParseHttpResponseCode");
+        assert_eq!(to_pascal_case("ǆungla_ßa"), "ǅunglaSsa");
+        assert_eq!(to_snake_case("ÓlegÜber"), "óleg_über");
+    }
+}
@@ -0,0 +1,285 @@
+// Python's `collections.defaultdict` and `collections.Counter`, both keep insertion order
+// like the Python versions since they are built on `Dict`
+//
+// let mut groups = DefaultMap::new(Vec::new);     // defaultdict(list)
+// groups.entry("a").push(1);                      // groups["a"].append(1)
+//
+// let tally: Tally<_> = "abracadabra".chars().collect();  // Counter("abracadabra")
+// tally.most_common(2)                                     // [('a', 5), ('b', 2)]
+
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, BitAnd, BitOr, Deref, DerefMut, Sub};
+
+use crate::dict::Dict;
+
+/// A `Dict` that creates missing values with `factory` when they're accessed through `entry`
+pub struct DefaultMap<K, V, F = fn() -> V> {
+    dict: Dict<K, V>,
+    factory: F,
+}
+
+impl<K: Hash + Eq + Clone, V, F: Fn() -> V> DefaultMap<K, V, F> {
+    pub fn new(factory: F) -> Self {
+        DefaultMap {
+            dict: Dict::new(),
+            factory,
+        }
+    }
+
+    /// Python's `d[key]`, inserts `factory()` for a missing key
+    pub fn entry(&mut self, key: K) -> &mut V {
+        if !self.dict.contains_key(&key) {
+            self.dict.insert(key.clone(), (self.factory)());
+        }
+        self.dict.get_mut(&key).expect("inserted above")
+    }
+
+    pub fn into_dict(self) -> Dict<K, V> {
+        self.dict
+    }
+}
+
+// like defaultdict subclasses dict, every Dict method works on a DefaultMap
+impl<K, V, F> Deref for DefaultMap<K, V, F> {
+    type Target = Dict<K, V>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.dict
+    }
+}
+
+impl<K, V, F> DerefMut for DefaultMap<K, V, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.dict
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, F> fmt::Debug for DefaultMap<K, V, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.dict.fmt(f)
+    }
+}
+
+/// Multiset, counts how many times every key was added
+#[derive(Clone)]
+pub struct Tally<K> {
+    counts: Dict<K, usize>,
+}
+
+impl<K> Default for Tally<K> {
+    fn default() -> Self {
+        Tally {
+            counts: Dict::default(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> Tally<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    pub fn add_n(&mut self, key: K, n: usize) {
+        if n > 0 {
+            *self.counts.setdefault(key, 0) += n;
+        }
+    }
+
+    /// Python's `c[key]`, 0 for keys that were never added
+    pub fn count<Q>(&self, key: &Q) -> usize
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Distinct keys
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Sum of all counts
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// The `n` most common keys, ties keep the insertion order like Python
+    pub fn most_common(&self, n: usize) -> Vec<(&K, usize)> {
+        let mut common: Vec<(&K, usize)> = self.items().collect();
+        // stable sort, so ties stay in insertion order
+        common.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        common.truncate(n);
+        common
+    }
+
+    /// Every key repeated as many times as it was counted
+    pub fn elements(&self) -> impl Iterator<Item = &K> {
+        self.counts
+            .items()
+            .flat_map(|(key, &count)| std::iter::repeat_n(key, count))
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = (&K, usize)> {
+        self.counts.items().map(|(key, &count)| (key, count))
+    }
+
+    // keys of self then the new keys of other, `op` decides the count and 0 drops the key
+    fn combine(&self, other: &Self, op: impl Fn(usize, usize) -> usize) -> Self {
+        let mut result = Tally::new();
+        let keys = self.counts.keys().chain(
+            other
+                .counts
+                .keys()
+                .filter(|key| !self.counts.contains_key(*key)),
+        );
+        for key in keys {
+            result.add_n(key.clone(), op(self.count(key), other.count(key)));
+        }
+        result
+    }
+}
+
+/// Same counts, the order doesn't matter
+impl<K: Hash + Eq + Clone> PartialEq for Tally<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.counts == other.counts
+    }
+}
+
+impl<K: Hash + Eq + Clone> Eq for Tally<K> {}
+
+impl<K: Hash + Eq + Clone> FromIterator<K> for Tally<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut tally = Tally::new();
+        tally.extend(iter);
+        tally
+    }
+}
+
+impl<K: Hash + Eq + Clone> Extend<K> for Tally<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+/// `c + d`, counts are summed
+impl<K: Hash + Eq + Clone> Add for &Tally<K> {
+    type Output = Tally<K>;
+
+    fn add(self, rhs: Self) -> Tally<K> {
+        self.combine(rhs, |a, b| a + b)
+    }
+}
+
+/// `c - d`, keys whose count drops to 0 or below are removed
+impl<K: Hash + Eq + Clone> Sub for &Tally<K> {
+    type Output = Tally<K>;
+
+    fn sub(self, rhs: Self) -> Tally<K> {
+        self.combine(rhs, |a, b| a.saturating_sub(b))
+    }
+}
+
+/// `c | d`, the larger count
+impl<K: Hash + Eq + Clone> BitOr for &Tally<K> {
+    type Output = Tally<K>;
+
+    fn bitor(self, rhs: Self) -> Tally<K> {
+        self.combine(rhs, usize::max)
+    }
+}
+
+/// `c & d`, the smaller count
+impl<K: Hash + Eq + Clone> BitAnd for &Tally<K> {
+    type Output = Tally<K>;
+
+    fn bitand(self, rhs: Self) -> Tally<K> {
+        self.combine(rhs, usize::min)
+    }
+}
+
+macro_rules! impl_owned_op {
+    ($($trait:ident $method:ident),*) => {
+        $(impl<K: Hash + Eq + Clone> $trait for Tally<K> {
+            type Output = Tally<K>;
+
+            fn $method(self, rhs: Self) -> Tally<K> {
+                (&self).$method(&rhs)
+            }
+        })*
+    };
+}
+
+impl_owned_op!(Add add, Sub sub, BitOr bitor, BitAnd bitand);
+
+/// `Tally({"a": 5, "b": 2})`, most common first like Python's Counter repr
+impl<K: Hash + Eq + Clone + fmt::Debug> fmt::Debug for Tally<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Tally(")?;
+        f.debug_map()
+            .entries(self.most_common(self.len()))
+            .finish()?;
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_map_creates_values() {
+        let mut groups = DefaultMap::new(Vec::new);
+        for (key, value) in [("b", 1), ("a", 2), ("b", 3)] {
+            groups.entry(key).push(value);
+        }
+        assert_eq!(format!("{:?}", groups), r#"{"b": [1, 3], "a": [2]}"#);
+        // reading doesn't create anything
+        assert_eq!(groups.get("c"), None);
+        assert_eq!(groups.len(), 2);
+
+        let mut counts = DefaultMap::new(|| 10);
+        *counts.entry("x") += 1;
+        assert_eq!(counts.into_dict(), crate::dict! { "x" => 11 });
+    }
+
+    // expected values are from Python 3.11
+    #[test]
+    fn counts_like_python() {
+        let tally: Tally<char> = "abracadabra".chars().collect();
+        assert_eq!(tally.most_common(2), vec![(&'a', 5), (&'b', 2)]);
+        assert_eq!(
+            format!("{:?}", tally),
+            "Tally({'a': 5, 'b': 2, 'r': 2, 'c': 1, 'd': 1})"
+        );
+        assert_eq!(tally.count(&'z'), 0);
+        assert_eq!(tally.total(), 11);
+        assert_eq!(tally.len(), 5);
+        let elements: String = tally.elements().collect();
+        assert_eq!(elements, "aaaaabbrrcd");
+    }
+
+    #[test]
+    fn arithmetic() {
+        let c: Tally<char> = "aaab".chars().collect();
+        let d: Tally<char> = "abbc".chars().collect();
+        assert_eq!(format!("{:?}", &c + &d), "Tally({'a': 4, 'b': 3, 'c': 1})");
+        assert_eq!(format!("{:?}", &c - &d), "Tally({'a': 2})");
+        assert_eq!(format!("{:?}", &c | &d), "Tally({'a': 3, 'b': 2, 'c': 1})");
+        assert_eq!(format!("{:?}", &c & &d), "Tally({'a': 1, 'b': 1})");
+        assert_eq!(c.clone() + Tally::new(), c);
+    }
+}
@@ -0,0 +1,170 @@
+// Runs the Python half of a lesson in an embedded interpreter and compares its output
+// with the Rust half, line by line.
+//
+// The Python versions live in python/ and need Python 3.11+ for `match` and `add_note`.
+
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::lessons::Lesson;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diff<'a> {
+    Same(&'a str),
+    Rust(&'a str),
+    Python(&'a str),
+}
+
+/// What a lesson printed, only stdout is compared, stderr has tracebacks with paths
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Captured {
+    pub stdout: String,
+    pub stderr: String,
+}
+
+pub struct Comparison {
+    pub rust: Captured,
+    pub python: Captured,
+}
+
+impl Comparison {
+    pub fn matches(&self) -> bool {
+        self.rust.stdout == self.python.stdout
+    }
+
+    pub fn diff(&self) -> Vec<Diff<'_>> {
+        diff(&self.rust.stdout, &self.python.stdout)
+    }
+}
+
+/// Runs the lesson through `exe run <id>`, as the lessons print straight to stdout
+pub fn capture_rust(exe: &Path, lesson: &Lesson) -> io::Result<Captured> {
+    let output = Command::new(exe).args(["run", lesson.id]).output()?;
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    if !output.status.success() {
+        return Err(io::Error::other(format!(
+            "rust version of {} failed ({}):\n{}",
+            lesson.id, output.status, stderr
+        )));
+    }
+    Ok(Captured {
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr,
+    })
+}
+
+/// Runs `code` as `__main__` with `sys.stdout` and `sys.stderr` swapped for StringIOs
+pub fn capture_python(code: &str) -> PyResult<Captured> {
+    pyo3::prepare_freethreaded_python();
+    Python::with_gil(|py| {
+        let sys = py.import("sys")?;
+        let io = py.import("io")?;
+        let (stdout, stderr) = (io.call_method0("StringIO")?, io.call_method0("StringIO")?);
+        let original = (sys.getattr("stdout")?, sys.getattr("stderr")?);
+
+        let globals = PyDict::new(py);
+        globals.set_item("__name__", "__main__")?;
+
+        sys.setattr("stdout", stdout)?;
+        sys.setattr("stderr", stderr)?;
+        let result = py.run(code, Some(globals), None);
+        sys.setattr("stdout", original.0)?;
+        sys.setattr("stderr", original.1)?;
+        result?;
+
+        Ok(Captured {
+            stdout: stdout.call_method0("getvalue")?.extract()?,
+            stderr: stderr.call_method0("getvalue")?.extract()?,
+        })
+    })
+}
+
+pub fn compare(exe: &Path, lesson: &Lesson) -> eyre::Result<Comparison> {
+    Ok(Comparison {
+        rust: capture_rust(exe, lesson)?,
+        python: capture_python(lesson.python)?,
+    })
+}
+
+// longest common subsequence of lines, outputs are small enough for the full table
+pub fn diff<'a>(rust: &'a str, python: &'a str) -> Vec<Diff<'a>> {
+    let a: Vec<&str> = rust.lines().collect();
+    let b: Vec<&str> = python.lines().collect();
+
+    let mut lcs = vec![vec![0_usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut diff = Vec::new();
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            diff.push(Diff::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            diff.push(Diff::Rust(a[i]));
+            i += 1;
+        } else {
+            diff.push(Diff::Python(b[j]));
+            j += 1;
+        }
+    }
+    diff.extend(a[i..].iter().map(|line| Diff::Rust(line)));
+    diff.extend(b[j..].iter().map(|line| Diff::Python(line)));
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lessons::LESSONS;
+
+    #[test]
+    fn diffs_lines() {
+        assert_eq!(
+            diff("a\nb\nc\n", "a\nc\nd\n"),
+            vec![
+                Diff::Same("a"),
+                Diff::Rust("b"),
+                Diff::Same("c"),
+                Diff::Python("d")
+            ]
+        );
+        assert!(diff("x\n", "x\n")
+            .iter()
+            .all(|d| matches!(d, Diff::Same(_))));
+    }
+
+    #[test]
+    fn captures_python_output() {
+        let output = capture_python("for i in range(3):\n    print(i)").unwrap();
+        assert_eq!(output.stdout, "0\n1\n2\n");
+        let output = capture_python("import sys\nprint('oops', file=sys.stderr)").unwrap();
+        assert_eq!(
+            (output.stdout.as_str(), output.stderr.as_str()),
+            ("", "oops\n")
+        );
+        assert!(capture_python("raise ValueError('tofu')").is_err());
+    }
+
+    #[test]
+    fn every_python_lesson_runs() {
+        for lesson in LESSONS {
+            if let Err(e) = capture_python(lesson.python) {
+                panic!("python version of {} failed: {}", lesson.id, e);
+            }
+        }
+    }
+}
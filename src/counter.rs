@@ -0,0 +1,268 @@
+// Python's `range` and `itertools.count` as one iterator
+//
+// Counter::new()                 // 1..=5, what the iterators lesson started with
+// Counter::range(10, 0, -3)      // range(10, 0, -3) -> 10, 7, 4, 1
+// Counter::count(0, 5)           // itertools.count(0, 5) -> 0, 5, 10, ...
+//
+// Bounded counters can also go backwards and know their length, unbounded ones stop
+// once the next value doesn't fit in `T` anymore
+
+use std::fmt;
+use std::iter::FusedIterator;
+
+use crate::guard::DropRecorder;
+
+/// Integers a Counter can count with, the math is done in i128 so nothing overflows
+pub trait Int: Copy + fmt::Debug {
+    fn to_i128(self) -> i128;
+    fn from_i128(n: i128) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                <$t>::try_from(n).ok()
+            }
+        })*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The end of a counter, either a value of the counted type or `Unbounded`
+pub trait End: Copy + fmt::Debug {
+    const BOUNDED: bool;
+}
+
+impl<T: Int> End for T {
+    const BOUNDED: bool = true;
+}
+
+/// A counter that never stops, like `itertools.count`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unbounded;
+
+impl End for Unbounded {
+    const BOUNDED: bool = false;
+}
+
+#[derive(Debug, Clone)]
+pub struct Counter<T: Int = u32, E: End = T> {
+    start: T,
+    end: E,
+    step: i128,
+    front: i128,
+    // values from start to end and how many are left, u128::MAX for unbounded counters
+    len: u128,
+    remaining: u128,
+    on_drop: Option<DropRecorder>,
+}
+
+impl Counter {
+    /// Counts 1 to 5
+    pub fn new() -> Self {
+        Counter::range(1, 6, 1)
+    }
+}
+
+impl Default for Counter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Int> Counter<T> {
+    /// `range(start, end, step)`, `end` is exclusive and `step` can be negative
+    pub fn range(start: T, end: T, step: i64) -> Self {
+        assert!(step != 0, "Counter step must not be zero");
+        // same as len(range(start, end, step)) in Python
+        let distance = match step > 0 {
+            true => end.to_i128() - start.to_i128(),
+            false => start.to_i128() - end.to_i128(),
+        };
+        let len = match distance > 0 {
+            true => (distance as u128).div_ceil(step.unsigned_abs().into()),
+            false => 0,
+        };
+        Counter {
+            start,
+            end,
+            step: step.into(),
+            front: start.to_i128(),
+            len,
+            remaining: len,
+            on_drop: None,
+        }
+    }
+}
+
+impl<T: Int> Counter<T, Unbounded> {
+    /// `itertools.count(start, step)`
+    pub fn count(start: T, step: i64) -> Self {
+        Counter {
+            start,
+            end: Unbounded,
+            step: step.into(),
+            front: start.to_i128(),
+            len: u128::MAX,
+            remaining: u128::MAX,
+            on_drop: None,
+        }
+    }
+}
+
+impl<T: Int, E: End> Counter<T, E> {
+    /// Starts over from `start`, even after the counter was used from the back
+    pub fn reset(&mut self) {
+        self.front = self.start.to_i128();
+        self.remaining = self.len;
+    }
+
+    pub fn start(&self) -> T {
+        self.start
+    }
+
+    pub fn end(&self) -> E {
+        self.end
+    }
+
+    pub fn step(&self) -> i64 {
+        self.step as i64
+    }
+
+    /// Records "Counter dropped at N" when the counter is dropped
+    pub fn record_drop(mut self, recorder: &DropRecorder) -> Self {
+        self.on_drop = Some(recorder.clone());
+        self
+    }
+
+    pub(crate) fn drop_recorder(&self) -> Option<&DropRecorder> {
+        self.on_drop.as_ref()
+    }
+
+    // the value `next` would return, used when the counter is dropped
+    pub(crate) fn current(&self) -> i128 {
+        self.front
+    }
+}
+
+impl<T: Int, E: End> Iterator for Counter<T, E> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        // an unbounded counter stops where `T` ends, without moving so it stays fused
+        let value = T::from_i128(self.front)?;
+        self.front += self.step;
+        if E::BOUNDED {
+            self.remaining -= 1;
+        }
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match E::BOUNDED {
+            true => (
+                usize::try_from(self.remaining).unwrap_or(usize::MAX),
+                usize::try_from(self.remaining).ok(),
+            ),
+            // it only stops where `T` ends
+            false => (0, None),
+        }
+    }
+}
+
+impl<T: Int> DoubleEndedIterator for Counter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        T::from_i128(self.front + self.remaining as i128 * self.step)
+    }
+}
+
+impl<T: Int, E: End> FusedIterator for Counter<T, E> {}
+
+// only where the length always fits in usize, same as std's ranges
+macro_rules! impl_exact_size {
+    ($($t:ty),*) => {
+        $(impl ExactSizeIterator for Counter<$t> {})*
+    };
+}
+
+impl_exact_size!(i8, i16, i32, isize, u8, u16, u32, usize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // expected values are from Python 3.11
+    #[test]
+    fn counts_like_range() {
+        assert_eq!(
+            Counter::range(0, 10, 3).collect::<Vec<_>>(),
+            vec![0, 3, 6, 9]
+        );
+        assert_eq!(
+            Counter::range(10, 0, -3).collect::<Vec<_>>(),
+            vec![10, 7, 4, 1]
+        );
+        assert_eq!(Counter::range(-2, 3, 2).collect::<Vec<_>>(), vec![-2, 0, 2]);
+        assert_eq!(Counter::range(5, 5, 1).count(), 0);
+        assert_eq!(Counter::range(0, 5, -1).count(), 0);
+        assert_eq!(Counter::range(0u8, 255, 1).len(), 255);
+        assert_eq!(Counter::range(10, 0, -3).len(), 4);
+    }
+
+    #[test]
+    fn both_ends() {
+        let mut counter = Counter::range(0, 10, 3);
+        assert_eq!(counter.next_back(), Some(9));
+        assert_eq!(counter.next(), Some(0));
+        assert_eq!(counter.len(), 2);
+        assert_eq!(counter.by_ref().rev().collect::<Vec<_>>(), vec![6, 3]);
+        assert_eq!(counter.next(), None);
+        assert_eq!(counter.next_back(), None);
+
+        counter.reset();
+        assert_eq!(counter.collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn unbounded() {
+        let mut counter = Counter::count(0i64, 5);
+        assert_eq!(counter.by_ref().take(3).collect::<Vec<_>>(), vec![0, 5, 10]);
+        assert_eq!(counter.size_hint(), (0, None));
+        counter.reset();
+        assert_eq!(counter.next(), Some(0));
+
+        // stops at the end of the type and stays stopped
+        let mut counter = Counter::count(250u8, 2);
+        assert_eq!(counter.by_ref().collect::<Vec<_>>(), vec![250, 252, 254]);
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn records_where_it_was_dropped() {
+        let recorder = DropRecorder::new();
+        let mut counter = Counter::new().record_drop(&recorder);
+        counter.next();
+        assert!(recorder.events().is_empty());
+        drop(counter);
+        assert_eq!(recorder.events(), vec!["Counter dropped at 2"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "step must not be zero")]
+    fn zero_step_panics() {
+        Counter::range(0, 10, 0);
+    }
+}
@@ -0,0 +1,426 @@
+// RFC 4180 CSV reader
+// Python equivalent is `csv.DictReader`, which is what `line.split(',')` should have been
+//
+// Handles quoted fields with commas and newlines, escaped quotes (""), CRLF line endings,
+// a UTF-8 BOM before the header and blank lines between records.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CsvError {
+    #[error("failed to read csv: {0}")]
+    Io(#[from] io::Error),
+    #[error("empty input, expected a header row")]
+    NoHeader,
+    #[error("line {line}, column {column}: {message}")]
+    Malformed {
+        line: usize,
+        column: usize,
+        message: &'static str,
+    },
+    #[error("line {line}: expected {expected} fields, found {found}")]
+    FieldCount {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("missing column '{0}'")]
+    MissingColumn(String),
+}
+
+/// Header names and their column indexes
+#[derive(Debug, Clone)]
+pub struct Headers {
+    names: Vec<String>,
+    indexes: HashMap<String, usize>,
+}
+
+impl Headers {
+    fn new(names: Vec<String>) -> Self {
+        let mut indexes = HashMap::new();
+        for (i, name) in names.iter().enumerate() {
+            // first column wins for duplicated names
+            indexes.entry(name.clone()).or_insert(i);
+        }
+        Self { names, indexes }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.indexes.get(name).copied()
+    }
+
+    /// Same as `index` but fails with `CsvError::MissingColumn`
+    pub fn require(&self, name: &str) -> Result<usize, CsvError> {
+        self.index(name)
+            .ok_or_else(|| CsvError::MissingColumn(name.to_string()))
+    }
+}
+
+/// A single row, `line` is where it starts in the input
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    line: usize,
+    fields: Vec<String>,
+}
+
+impl Record {
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.fields.get(index).map(String::as_str)
+    }
+
+    pub fn fields(&self) -> &[String] {
+        &self.fields
+    }
+}
+
+#[derive(Clone, Copy)]
+enum State {
+    FieldStart,
+    Unquoted,
+    Quoted,
+    // a quote inside a quoted field, either closes it or escapes the next quote
+    QuoteInQuoted,
+}
+
+// Line-by-line state machine, shared by the blocking and the async reader
+struct Parser {
+    line: usize,
+    state: State,
+    // line the current record started at
+    start: usize,
+    quote_at: (usize, usize),
+    fields: Vec<String>,
+    field: String,
+}
+
+impl Parser {
+    fn new() -> Self {
+        Self {
+            line: 0,
+            state: State::FieldStart,
+            start: 0,
+            quote_at: (0, 0),
+            fields: Vec::new(),
+            field: String::new(),
+        }
+    }
+
+    // returns a record once its last line was fed
+    fn feed(&mut self, line: &str) -> Result<Option<Record>, CsvError> {
+        self.line += 1;
+        let line = match self.line {
+            1 => line.strip_prefix('\u{feff}').unwrap_or(line),
+            _ => line,
+        };
+        let (content, eol) = split_eol(line);
+
+        // anything but an open quote means this line starts a new record
+        if !matches!(self.state, State::Quoted) {
+            if content.is_empty() {
+                return Ok(None);
+            }
+            self.start = self.line;
+            self.state = State::FieldStart;
+            self.fields.clear();
+            self.field.clear();
+        }
+
+        for (i, c) in content.chars().enumerate() {
+            let malformed = |message| CsvError::Malformed {
+                line: self.line,
+                column: i + 1,
+                message,
+            };
+            self.state = match (self.state, c) {
+                (State::FieldStart, '"') => {
+                    self.quote_at = (self.line, i + 1);
+                    State::Quoted
+                }
+                (State::FieldStart | State::Unquoted | State::QuoteInQuoted, ',') => {
+                    self.fields.push(std::mem::take(&mut self.field));
+                    State::FieldStart
+                }
+                (State::Unquoted, '"') => {
+                    return Err(malformed("unexpected quote in an unquoted field"))
+                }
+                (State::FieldStart | State::Unquoted, c) => {
+                    self.field.push(c);
+                    State::Unquoted
+                }
+                (State::Quoted, '"') => State::QuoteInQuoted,
+                (State::Quoted, c) => {
+                    self.field.push(c);
+                    State::Quoted
+                }
+                (State::QuoteInQuoted, '"') => {
+                    self.field.push('"');
+                    State::Quoted
+                }
+                (State::QuoteInQuoted, _) => {
+                    return Err(malformed("unexpected character after a closing quote"))
+                }
+            };
+        }
+
+        if let State::Quoted = self.state {
+            // quoted fields keep their line breaks
+            self.field.push_str(eol);
+            return Ok(None);
+        }
+
+        self.fields.push(std::mem::take(&mut self.field));
+        self.state = State::FieldStart;
+        Ok(Some(Record {
+            line: self.start,
+            fields: std::mem::take(&mut self.fields),
+        }))
+    }
+
+    // called at the end of the input
+    fn finish(&mut self) -> Result<(), CsvError> {
+        if let State::Quoted = self.state {
+            let (line, column) = self.quote_at;
+            self.state = State::FieldStart;
+            return Err(CsvError::Malformed {
+                line,
+                column,
+                message: "unterminated quoted field",
+            });
+        }
+        Ok(())
+    }
+}
+
+fn check_field_count(headers: &Headers, record: Record) -> Result<Record, CsvError> {
+    if record.fields.len() != headers.len() {
+        return Err(CsvError::FieldCount {
+            line: record.line,
+            expected: headers.len(),
+            found: record.fields.len(),
+        });
+    }
+    Ok(record)
+}
+
+pub struct Reader<R> {
+    inner: R,
+    headers: Headers,
+    parser: Parser,
+}
+
+impl Reader<BufReader<File>> {
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, CsvError> {
+        Self::new(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: BufRead> Reader<R> {
+    /// Reads the header row straight away
+    pub fn new(inner: R) -> Result<Self, CsvError> {
+        let mut reader = Self {
+            inner,
+            headers: Headers::new(Vec::new()),
+            parser: Parser::new(),
+        };
+        let header = reader.read_record()?.ok_or(CsvError::NoHeader)?;
+        reader.headers = Headers::new(header.fields);
+        Ok(reader)
+    }
+
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    fn read_record(&mut self) -> Result<Option<Record>, CsvError> {
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            if self.inner.read_line(&mut buf)? == 0 {
+                self.parser.finish()?;
+                return Ok(None);
+            }
+            if let Some(record) = self.parser.feed(&buf)? {
+                return Ok(Some(record));
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Reader<R> {
+    type Item = Result<Record, CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.read_record().transpose()?;
+        Some(record.and_then(|record| check_field_count(&self.headers, record)))
+    }
+}
+
+/// Same as `Reader`, but reads lines with async-std so other tasks can run in the meantime
+pub struct AsyncReader<R> {
+    inner: R,
+    headers: Headers,
+    parser: Parser,
+}
+
+impl AsyncReader<async_std::io::BufReader<async_std::fs::File>> {
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, CsvError> {
+        let file = async_std::fs::File::open(path.as_ref()).await?;
+        Self::new(async_std::io::BufReader::new(file)).await
+    }
+}
+
+impl<R: async_std::io::BufRead + Unpin> AsyncReader<R> {
+    pub async fn new(inner: R) -> Result<Self, CsvError> {
+        let mut reader = Self {
+            inner,
+            headers: Headers::new(Vec::new()),
+            parser: Parser::new(),
+        };
+        let header = reader.read_record().await?.ok_or(CsvError::NoHeader)?;
+        reader.headers = Headers::new(header.fields);
+        Ok(reader)
+    }
+
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    async fn read_record(&mut self) -> Result<Option<Record>, CsvError> {
+        use async_std::io::prelude::BufReadExt;
+
+        let mut buf = String::new();
+        loop {
+            buf.clear();
+            if self.inner.read_line(&mut buf).await? == 0 {
+                self.parser.finish()?;
+                return Ok(None);
+            }
+            if let Some(record) = self.parser.feed(&buf)? {
+                return Ok(Some(record));
+            }
+        }
+    }
+
+    /// Async counterpart of `Iterator::next`
+    pub async fn next_record(&mut self) -> Option<Result<Record, CsvError>> {
+        let record = self.read_record().await.transpose()?;
+        Some(record.and_then(|record| check_field_count(&self.headers, record)))
+    }
+}
+
+fn split_eol(line: &str) -> (&str, &str) {
+    let content = line
+        .strip_suffix("\r\n")
+        .or_else(|| line.strip_suffix('\n'))
+        .unwrap_or(line);
+    line.split_at(content.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn records(input: &str) -> Vec<Vec<String>> {
+        Reader::new(input.as_bytes())
+            .unwrap()
+            .map(|r| r.unwrap().fields)
+            .collect()
+    }
+
+    #[test]
+    fn maps_headers_to_indexes() {
+        let reader = Reader::new("\u{feff}Country,Date,Amount\r\n".as_bytes()).unwrap();
+        assert_eq!(reader.headers().index("Country"), Some(0));
+        assert_eq!(reader.headers().index("Amount"), Some(2));
+        assert!(matches!(
+            reader.headers().require("Price"),
+            Err(CsvError::MissingColumn(_))
+        ));
+    }
+
+    #[test]
+    fn handles_quotes_crlf_and_blank_lines() {
+        let input = "a,b\r\n\r\n\"x, y\",\"say \"\"hi\"\"\"\r\n,\n\"multi\nline\",z\n";
+        assert_eq!(
+            records(input),
+            vec![
+                vec!["x, y".to_string(), "say \"hi\"".to_string()],
+                vec![String::new(), String::new()],
+                vec!["multi\nline".to_string(), "z".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_line_and_column() {
+        let mut reader = Reader::new("a,b\n1,2\n3,x\"y\n".as_bytes()).unwrap();
+        assert_eq!(reader.next().unwrap().unwrap().line(), 2);
+        match reader.next().unwrap() {
+            Err(CsvError::Malformed { line, column, .. }) => assert_eq!((line, column), (3, 4)),
+            other => panic!("expected malformed row, got {:?}", other),
+        }
+
+        let mut reader = Reader::new("a,b\n\"open,2\n".as_bytes()).unwrap();
+        match reader.next().unwrap() {
+            Err(CsvError::Malformed { line, column, .. }) => assert_eq!((line, column), (2, 1)),
+            other => panic!("expected unterminated quote, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn checks_field_count() {
+        let mut reader = Reader::new("a,b\n1,2,3\n".as_bytes()).unwrap();
+        assert!(matches!(
+            reader.next(),
+            Some(Err(CsvError::FieldCount {
+                line: 2,
+                expected: 2,
+                found: 3
+            }))
+        ));
+    }
+
+    #[test]
+    fn async_reader_streams_the_same_records() {
+        let input = "a,b\r\n\"x\ny\",1\n\n2,3\n";
+        let records = async_std::task::block_on(async {
+            let mut reader = AsyncReader::new(input.as_bytes()).await.unwrap();
+            assert_eq!(reader.headers().index("b"), Some(1));
+            let mut records = Vec::new();
+            while let Some(record) = reader.next_record().await {
+                records.push(record.unwrap().fields);
+            }
+            records
+        });
+        assert_eq!(records, self::records(input));
+    }
+
+    #[test]
+    fn empty_input_has_no_header() {
+        assert!(matches!(
+            Reader::new("\n\n".as_bytes()),
+            Err(CsvError::NoHeader)
+        ));
+    }
+}
@@ -0,0 +1,325 @@
+// Insertion ordered map with Python's dict methods
+// `HashMap` iterates in a random order, Python dicts (3.7+) keep the order keys were inserted in
+//
+// let mut d = dict! { "a" => 1, "b" => 2 };
+// d.setdefault("c", 0);      // d.setdefault("c", 0)
+// d.get_or("z", &-1);        // d.get("z", -1)
+// println!("{:?}", d);       // {"a": 1, "b": 2, "c": 0}
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::Index;
+
+/// `dict! { "a" => 1, "b" => 2 }`, same as `hashmap!` but keeps the order
+#[macro_export]
+macro_rules! dict {
+    () => {
+        $crate::dict::Dict::new()
+    };
+    ($($key:expr => $value:expr),+ $(,)?) => {{
+        let mut dict = $crate::dict::Dict::new();
+        $(dict.insert($key, $value);)+
+        dict
+    }};
+}
+
+#[derive(Clone)]
+pub struct Dict<K, V> {
+    entries: Vec<(K, V)>,
+    // key to position in `entries`
+    indexes: HashMap<K, usize>,
+}
+
+impl<K, V> Default for Dict<K, V> {
+    fn default() -> Self {
+        Dict {
+            entries: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Dict<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// `d[key] = value`, an existing key keeps its position and the old value is returned
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.indexes.get(&key) {
+            Some(&i) => Some(std::mem::replace(&mut self.entries[i].1, value)),
+            None => {
+                self.indexes.insert(key.clone(), self.entries.len());
+                self.entries.push((key, value));
+                None
+            }
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &i = self.indexes.get(key)?;
+        Some(&self.entries[i].1)
+    }
+
+    /// `d.get(key, default)`
+    pub fn get_or<'a, Q>(&'a self, key: &Q, default: &'a V) -> &'a V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).unwrap_or(default)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let &i = self.indexes.get(key)?;
+        Some(&mut self.entries[i].1)
+    }
+
+    /// `key in d`
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indexes.contains_key(key)
+    }
+
+    /// `d.setdefault(key, default)`, inserts `default` if the key is missing
+    pub fn setdefault(&mut self, key: K, default: V) -> &mut V {
+        let i = match self.indexes.get(&key) {
+            Some(&i) => i,
+            None => {
+                self.insert(key, default);
+                self.entries.len() - 1
+            }
+        };
+        &mut self.entries[i].1
+    }
+
+    /// `d.pop(key)`, None instead of KeyError. O(n), every entry after `key` gets reindexed
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let i = self.indexes.remove(key)?;
+        let (_, value) = self.entries.remove(i);
+        // everything after the removed entry moved one position down
+        for (key, _) in &self.entries[i..] {
+            if let Some(index) = self.indexes.get_mut::<K>(key) {
+                *index -= 1;
+            }
+        }
+        Some(value)
+    }
+
+    /// `d.pop(key, default)`
+    pub fn pop_or<Q>(&mut self, key: &Q, default: V) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.pop(key).unwrap_or(default)
+    }
+
+    /// `d.popitem()`, removes the last inserted entry
+    pub fn popitem(&mut self) -> Option<(K, V)> {
+        let (key, value) = self.entries.pop()?;
+        self.indexes.remove(&key);
+        Some((key, value))
+    }
+
+    /// `d.update(other)`, existing keys are overwritten in place, new ones are appended
+    pub fn update(&mut self, other: impl IntoIterator<Item = (K, V)>) {
+        for (key, value) in other {
+            self.insert(key, value);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.indexes.clear();
+    }
+}
+
+impl<K, V> Dict<K, V> {
+    /// `d.items()`, in insertion order
+    pub fn items(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> + ExactSizeIterator {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.entries.iter().map(|(_, value)| value)
+    }
+}
+
+/// `d[key]`, panics for a missing key like Python raises KeyError
+impl<K, V, Q> Index<&Q> for Dict<K, V>
+where
+    K: Hash + Eq + Clone + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("KeyError: key not found in Dict")
+    }
+}
+
+/// Same keys and values, the order doesn't matter, like `==` on Python dicts
+impl<K: Hash + Eq + Clone, V: PartialEq> PartialEq for Dict<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .entries
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Eq> Eq for Dict<K, V> {}
+
+impl<K: Hash + Eq + Clone, V> FromIterator<(K, V)> for Dict<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut dict = Dict::new();
+        dict.update(iter);
+        dict
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Extend<(K, V)> for Dict<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        self.update(iter);
+    }
+}
+
+impl<K, V> IntoIterator for Dict<K, V> {
+    type Item = (K, V);
+    type IntoIter = std::vec::IntoIter<(K, V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a Dict<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = std::iter::Map<std::slice::Iter<'a, (K, V)>, fn(&'a (K, V)) -> (&'a K, &'a V)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
+
+/// `{"a": 1, "b": 2}`, same as HashMap's Debug but always in insertion order
+impl<K: fmt::Debug, V: fmt::Debug> fmt::Debug for Dict<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.items()).finish()
+    }
+}
+
+/// `{a: 1, b: 2}`
+impl<K: fmt::Display, V: fmt::Display> fmt::Display for Dict<K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, (key, value)) in self.items().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}: {}", key, value)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_insertion_order() {
+        let mut d = dict! { "b" => 1, "a" => 2, "c" => 3 };
+        assert_eq!(format!("{:?}", d), r#"{"b": 1, "a": 2, "c": 3}"#);
+        assert_eq!(d.to_string(), "{b: 1, a: 2, c: 3}");
+
+        // overwriting keeps the position
+        assert_eq!(d.insert("b", 10), Some(1));
+        assert_eq!(d.keys().copied().collect::<Vec<_>>(), vec!["b", "a", "c"]);
+        assert_eq!(d["b"], 10);
+        let empty: Dict<&str, i32> = dict! {};
+        assert_eq!(format!("{:?}", empty), "{}");
+    }
+
+    // expected values are from Python 3.11
+    #[test]
+    fn python_methods() {
+        let mut d = dict! { "one" => 1, "two" => 2, "three" => 3 };
+        assert_eq!(d.get_or("four", &4), &4);
+        assert_eq!(d.get("two"), Some(&2));
+
+        *d.setdefault("four", 0) += 4;
+        *d.setdefault("one", 0) += 10;
+        assert_eq!(
+            format!("{:?}", d),
+            r#"{"one": 11, "two": 2, "three": 3, "four": 4}"#
+        );
+
+        assert_eq!(d.pop("two"), Some(2));
+        assert_eq!(d.pop("two"), None);
+        assert_eq!(d.pop_or("two", -1), -1);
+        // indexes after the popped entry still work
+        assert_eq!(d.get("three"), Some(&3));
+        assert_eq!(d.get("four"), Some(&4));
+
+        d.update(dict! { "one" => 1, "five" => 5 });
+        assert_eq!(
+            format!("{:?}", d),
+            r#"{"one": 1, "three": 3, "four": 4, "five": 5}"#
+        );
+        assert_eq!(d.popitem(), Some(("five", 5)));
+        assert_eq!(d.popitem(), Some(("four", 4)));
+        assert_eq!(d.len(), 2);
+        assert!(!d.contains_key("four"));
+        d.clear();
+        assert_eq!(d.popitem(), None);
+    }
+
+    #[test]
+    fn compares_like_python() {
+        let a = dict! { "a" => 1, "b" => 2 };
+        let b: Dict<_, _> = vec![("b", 2), ("a", 1)].into_iter().collect();
+        assert_eq!(a, b);
+        assert_ne!(a, dict! { "a" => 1 });
+        let pairs: Vec<(&str, i32)> = b.into_iter().collect();
+        assert_eq!(pairs, vec![("b", 2), ("a", 1)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "KeyError")]
+    fn missing_key_panics() {
+        let d = dict! { "a" => 1 };
+        let _ = d["b"];
+    }
+}
@@ -0,0 +1,384 @@
+// Checked arithmetic expression evaluator, i.e. "10 * (2 + 3) / 4"
+// Python equivalent is `eval`, minus running arbitrary code and plus overflow checks
+//
+// Supports + - * / and parentheses on any integer or float type, `eval::<i32>` truncates
+// divisions like Rust does, `eval::<f64>` doesn't.
+
+use std::num::{IntErrorKind, ParseIntError};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ExprError {
+    #[error("unexpected character '{found}' at column {column}")]
+    BadToken { column: usize, found: char },
+    #[error("invalid number '{text}' at column {column}")]
+    InvalidNumber { column: usize, text: String },
+    #[error("unexpected '{found}' at column {column}")]
+    Unexpected { column: usize, found: String },
+    #[error("unexpected end of expression")]
+    UnexpectedEnd,
+    #[error("division by zero")]
+    DivisionByZero,
+    #[error("arithmetic overflow")]
+    Overflow,
+    #[error("expression nested more than {MAX_DEPTH} levels deep")]
+    TooDeep,
+}
+
+/// Numbers an expression can be evaluated as, every operation is checked
+pub trait Number: Copy + FromStr {
+    fn try_add(self, rhs: Self) -> Result<Self, ExprError>;
+    fn try_sub(self, rhs: Self) -> Result<Self, ExprError>;
+    fn try_mul(self, rhs: Self) -> Result<Self, ExprError>;
+    fn try_div(self, rhs: Self) -> Result<Self, ExprError>;
+    fn try_neg(self) -> Result<Self, ExprError>;
+    /// A literal that doesn't fit in `Self` is an overflow, not an invalid number
+    fn from_literal(text: &str, column: usize) -> Result<Self, ExprError>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            fn try_add(self, rhs: Self) -> Result<Self, ExprError> {
+                self.checked_add(rhs).ok_or(ExprError::Overflow)
+            }
+
+            fn try_sub(self, rhs: Self) -> Result<Self, ExprError> {
+                self.checked_sub(rhs).ok_or(ExprError::Overflow)
+            }
+
+            fn try_mul(self, rhs: Self) -> Result<Self, ExprError> {
+                self.checked_mul(rhs).ok_or(ExprError::Overflow)
+            }
+
+            fn try_div(self, rhs: Self) -> Result<Self, ExprError> {
+                if rhs == 0 {
+                    return Err(ExprError::DivisionByZero);
+                }
+                // MIN / -1
+                self.checked_div(rhs).ok_or(ExprError::Overflow)
+            }
+
+            fn try_neg(self) -> Result<Self, ExprError> {
+                self.checked_neg().ok_or(ExprError::Overflow)
+            }
+
+            fn from_literal(text: &str, column: usize) -> Result<Self, ExprError> {
+                text.parse().map_err(|e: ParseIntError| match e.kind() {
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ExprError::Overflow,
+                    _ => invalid_number(text, column),
+                })
+            }
+        })*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_float {
+    ($($t:ty),*) => {
+        $(impl Number for $t {
+            fn try_add(self, rhs: Self) -> Result<Self, ExprError> {
+                finite(self + rhs)
+            }
+
+            fn try_sub(self, rhs: Self) -> Result<Self, ExprError> {
+                finite(self - rhs)
+            }
+
+            fn try_mul(self, rhs: Self) -> Result<Self, ExprError> {
+                finite(self * rhs)
+            }
+
+            fn try_div(self, rhs: Self) -> Result<Self, ExprError> {
+                if rhs == 0.0 {
+                    return Err(ExprError::DivisionByZero);
+                }
+                finite(self / rhs)
+            }
+
+            fn try_neg(self) -> Result<Self, ExprError> {
+                Ok(-self)
+            }
+
+            fn from_literal(text: &str, column: usize) -> Result<Self, ExprError> {
+                finite(text.parse().map_err(|_| invalid_number(text, column))?)
+            }
+        })*
+    };
+}
+
+impl_float!(f32, f64);
+
+// floats don't wrap around, they become infinite instead
+fn finite<T: Into<f64> + Copy>(value: T) -> Result<T, ExprError> {
+    if value.into().is_finite() {
+        Ok(value)
+    } else {
+        Err(ExprError::Overflow)
+    }
+}
+
+pub fn eval<T: Number>(input: &str) -> Result<T, ExprError> {
+    let mut parser = Parser {
+        tokens: tokenize(input)?,
+        pos: 0,
+    };
+    let value = parser.expr(0)?;
+    match parser.tokens.get(parser.pos) {
+        None => Ok(value),
+        Some(token) => Err(token.unexpected()),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Kind {
+    Number(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Open,
+    Close,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: Kind,
+    column: usize,
+}
+
+impl Token {
+    fn unexpected(&self) -> ExprError {
+        let found = match &self.kind {
+            Kind::Number(text) => text.as_str(),
+            Kind::Plus => "+",
+            Kind::Minus => "-",
+            Kind::Star => "*",
+            Kind::Slash => "/",
+            Kind::Open => "(",
+            Kind::Close => ")",
+        };
+        ExprError::Unexpected {
+            column: self.column,
+            found: found.to_string(),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().enumerate().peekable();
+    while let Some((i, c)) = chars.next() {
+        let kind = match c {
+            c if c.is_whitespace() => continue,
+            '+' => Kind::Plus,
+            '-' => Kind::Minus,
+            '*' => Kind::Star,
+            '/' => Kind::Slash,
+            '(' => Kind::Open,
+            ')' => Kind::Close,
+            c if c.is_ascii_digit() || c == '.' => {
+                let mut text = c.to_string();
+                while let Some((_, c)) = chars.next_if(|(_, c)| c.is_ascii_digit() || *c == '.') {
+                    text.push(c);
+                }
+                Kind::Number(text)
+            }
+            found => {
+                return Err(ExprError::BadToken {
+                    column: i + 1,
+                    found,
+                })
+            }
+        };
+        tokens.push(Token {
+            kind,
+            column: i + 1,
+        });
+    }
+    Ok(tokens)
+}
+
+// recursive descent, one method per precedence level:
+// expr   = term (("+" | "-") term)*
+// term   = factor (("*" | "/") factor)*
+// factor = ("-" | "+") factor | number | "(" expr ")"
+//
+// every "(" and unary sign is one level of recursion, `depth` counts them so that
+// "((((..." returns TooDeep instead of overflowing the stack
+const MAX_DEPTH: usize = 256;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Result<Token, ExprError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(ExprError::UnexpectedEnd)?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<&Kind> {
+        self.tokens.get(self.pos).map(|token| &token.kind)
+    }
+
+    fn expr<T: Number>(&mut self, depth: usize) -> Result<T, ExprError> {
+        let mut value: T = self.term(depth)?;
+        loop {
+            match self.peek() {
+                Some(Kind::Plus) => {
+                    self.pos += 1;
+                    value = value.try_add(self.term(depth)?)?;
+                }
+                Some(Kind::Minus) => {
+                    self.pos += 1;
+                    value = value.try_sub(self.term(depth)?)?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn term<T: Number>(&mut self, depth: usize) -> Result<T, ExprError> {
+        let mut value: T = self.factor(depth)?;
+        loop {
+            match self.peek() {
+                Some(Kind::Star) => {
+                    self.pos += 1;
+                    value = value.try_mul(self.factor(depth)?)?;
+                }
+                Some(Kind::Slash) => {
+                    self.pos += 1;
+                    value = value.try_div(self.factor(depth)?)?;
+                }
+                _ => return Ok(value),
+            }
+        }
+    }
+
+    fn factor<T: Number>(&mut self, depth: usize) -> Result<T, ExprError> {
+        if depth > MAX_DEPTH {
+            return Err(ExprError::TooDeep);
+        }
+        let token = self.next()?;
+        match token.kind {
+            Kind::Minus => match self.peek() {
+                // parsed together, so i32::MIN doesn't overflow before it gets negated
+                Some(Kind::Number(text)) => {
+                    let text = format!("-{}", text);
+                    self.pos += 1;
+                    T::from_literal(&text, token.column)
+                }
+                _ => self.factor::<T>(depth + 1)?.try_neg(),
+            },
+            Kind::Plus => self.factor(depth + 1),
+            Kind::Number(ref text) => T::from_literal(text, token.column),
+            Kind::Open => {
+                let value = self.expr(depth + 1)?;
+                let close = self.next()?;
+                match close.kind {
+                    Kind::Close => Ok(value),
+                    _ => Err(close.unexpected()),
+                }
+            }
+            Kind::Star | Kind::Slash | Kind::Close => Err(token.unexpected()),
+        }
+    }
+}
+
+fn invalid_number(text: &str, column: usize) -> ExprError {
+    ExprError::InvalidNumber {
+        column,
+        text: text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_and_float() {
+        assert_eq!(eval::<i32>("10 * (2 + 3) / 4"), Ok(12));
+        assert_eq!(eval::<f64>("10 * (2 + 3) / 4"), Ok(12.5));
+        assert_eq!(eval::<i64>("2 + 3 * 4 - -1"), Ok(15));
+        assert_eq!(eval::<f32>("-(1.5 + .5) * 2"), Ok(-4.0));
+        assert_eq!(eval::<i32>("-2147483648"), Ok(i32::MIN));
+        assert_eq!(eval::<u8>("  42 "), Ok(42));
+    }
+
+    #[test]
+    fn bad_tokens() {
+        assert_eq!(
+            eval::<i32>("tofu"),
+            Err(ExprError::BadToken {
+                column: 1,
+                found: 't'
+            })
+        );
+        assert_eq!(
+            eval::<i32>("1.5 * 2"),
+            Err(ExprError::InvalidNumber {
+                column: 1,
+                text: "1.5".to_string()
+            })
+        );
+        assert_eq!(eval::<i32>("(1 + 2"), Err(ExprError::UnexpectedEnd));
+        assert_eq!(
+            eval::<i32>("1 2"),
+            Err(ExprError::Unexpected {
+                column: 3,
+                found: "2".to_string()
+            })
+        );
+        assert_eq!(eval::<i32>(""), Err(ExprError::UnexpectedEnd));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(eval::<i32>("1 / (2 - 2)"), Err(ExprError::DivisionByZero));
+        assert_eq!(eval::<f64>("1 / 0"), Err(ExprError::DivisionByZero));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(eval::<i32>("2147483647 + 1"), Err(ExprError::Overflow));
+        assert_eq!(eval::<i32>("-2147483648 / -1"), Err(ExprError::Overflow));
+        assert_eq!(eval::<u32>("1 - 2"), Err(ExprError::Overflow));
+        assert_eq!(
+            eval::<f32>("340000000000000000000000000000000000000 * 10"),
+            Err(ExprError::Overflow)
+        );
+        // literals that don't fit
+        assert_eq!(eval::<i32>("2147483648"), Err(ExprError::Overflow));
+        assert_eq!(eval::<i32>("-2147483649"), Err(ExprError::Overflow));
+        assert_eq!(eval::<u8>("256 - 1"), Err(ExprError::Overflow));
+        let huge = format!("1{}", "0".repeat(400));
+        assert_eq!(eval::<f64>(&huge), Err(ExprError::Overflow));
+        assert_eq!(eval::<f64>(&format!("-{}", huge)), Err(ExprError::Overflow));
+        assert_eq!(
+            eval::<f32>(&format!("1{}", "0".repeat(40))),
+            Err(ExprError::Overflow)
+        );
+    }
+
+    #[test]
+    fn deep_nesting_is_an_error() {
+        let parens = format!("{}1{}", "(".repeat(200_000), ")".repeat(200_000));
+        assert_eq!(eval::<i32>(&parens), Err(ExprError::TooDeep));
+        let signs = format!("{}(1)", "-".repeat(200_000));
+        assert_eq!(eval::<i64>(&signs), Err(ExprError::TooDeep));
+        assert_eq!(eval::<i32>(&"(".repeat(200_000)), Err(ExprError::TooDeep));
+        let ok = format!("{}1{}", "(".repeat(MAX_DEPTH), ")".repeat(MAX_DEPTH));
+        assert_eq!(eval::<i32>(&ok), Ok(1));
+    }
+}
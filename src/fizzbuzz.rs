@@ -0,0 +1,316 @@
+// FizzBuzz as a rule engine, the rules are checked in order and the words of every matching
+// rule are joined, a number nothing matches is printed as is
+//
+// let fizz_buzz = FizzBuzz::new().divisor(3, "Fizz").divisor(5, "Buzz").range(1..16);
+// fizz_buzz.iter() -> "1", "2", "Fizz", "4", "Buzz", ..., "FizzBuzz"
+//
+// Rule sets can be loaded from a config file, one `matcher = word` per line:
+//
+// # comment
+// range = 1..=21
+// 3 = Fizz
+// contains 7 = Lucky
+// prime = Prime
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Bound, RangeBounds};
+use std::path::Path;
+use std::str::FromStr;
+
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FizzBuzzError {
+    #[error("could not read rules: {0}")]
+    Io(#[from] io::Error),
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+}
+
+pub enum Matcher {
+    Divisor(i64),
+    Predicate(Box<dyn Fn(i64) -> bool>),
+}
+
+impl Matcher {
+    pub fn matches(&self, n: i64) -> bool {
+        match self {
+            // a divisor of 0 never matches instead of panicking
+            Matcher::Divisor(divisor) => n.checked_rem(*divisor) == Some(0),
+            Matcher::Predicate(predicate) => predicate(n),
+        }
+    }
+}
+
+impl fmt::Debug for Matcher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Matcher::Divisor(divisor) => write!(f, "Divisor({})", divisor),
+            Matcher::Predicate(_) => write!(f, "Predicate(..)"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Rule {
+    pub matcher: Matcher,
+    pub word: String,
+}
+
+#[derive(Debug)]
+pub struct FizzBuzz {
+    rules: Vec<Rule>,
+    start: i64,
+    /// inclusive, i64::MAX when there is no end
+    end: i64,
+}
+
+impl Default for FizzBuzz {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FizzBuzz {
+    /// No rules, from 1 to 100
+    pub fn new() -> Self {
+        FizzBuzz {
+            rules: Vec::new(),
+            start: 1,
+            end: 100,
+        }
+    }
+
+    /// 3 is Fizz, 5 is Buzz
+    pub fn classic() -> Self {
+        Self::new().divisor(3, "Fizz").divisor(5, "Buzz")
+    }
+
+    pub fn divisor(self, divisor: i64, word: impl Into<String>) -> Self {
+        self.with_rule(Matcher::Divisor(divisor), word)
+    }
+
+    pub fn rule(self, predicate: impl Fn(i64) -> bool + 'static, word: impl Into<String>) -> Self {
+        self.with_rule(Matcher::Predicate(Box::new(predicate)), word)
+    }
+
+    fn with_rule(mut self, matcher: Matcher, word: impl Into<String>) -> Self {
+        self.rules.push(Rule {
+            matcher,
+            word: word.into(),
+        });
+        self
+    }
+
+    /// `1..16`, `1..=15`, or `1..` to go on forever
+    pub fn range(mut self, range: impl RangeBounds<i64>) -> Self {
+        self.start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => i64::MIN,
+        };
+        self.end = match range.end_bound() {
+            Bound::Included(&end) => end,
+            Bound::Excluded(&end) => end.saturating_sub(1),
+            Bound::Unbounded => i64::MAX,
+        };
+        self
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// The words of every matching rule, or the number itself
+    pub fn word(&self, n: i64) -> String {
+        let word: String = self
+            .rules
+            .iter()
+            .filter(|rule| rule.matcher.matches(n))
+            .map(|rule| rule.word.as_str())
+            .collect();
+        match word.is_empty() {
+            true => n.to_string(),
+            false => word,
+        }
+    }
+
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            fizz_buzz: self,
+            next: Some(self.start).filter(|&start| start <= self.end),
+        }
+    }
+
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, FizzBuzzError> {
+        fs::read_to_string(path)?.parse()
+    }
+}
+
+/// Lazy, nothing is computed until `next` is called
+pub struct Iter<'a> {
+    fizz_buzz: &'a FizzBuzz,
+    next: Option<i64>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.next?;
+        self.next = n.checked_add(1).filter(|&next| next <= self.fizz_buzz.end);
+        Some(self.fizz_buzz.word(n))
+    }
+}
+
+impl<'a> IntoIterator for &'a FizzBuzz {
+    type Item = String;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromStr for FizzBuzz {
+    type Err = FizzBuzzError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fizz_buzz = FizzBuzz::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: String| FizzBuzzError::Parse {
+                line: i + 1,
+                message,
+            };
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error(format!(
+                    "expected `matcher = word`, found '{}'",
+                    line
+                )));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            if key == "range" {
+                fizz_buzz = parse_range(fizz_buzz, value).map_err(error)?;
+                continue;
+            }
+            if value.is_empty() {
+                return Err(error(format!("no word for '{}'", key)));
+            }
+            fizz_buzz = match (key, key.split_once(' ')) {
+                ("prime", _) => fizz_buzz.rule(is_prime, value),
+                (_, Some(("contains", digits))) => {
+                    let digits = digits.trim().to_string();
+                    if digits.parse::<u64>().is_err() {
+                        return Err(error(format!("'{}' is not a number", digits)));
+                    }
+                    fizz_buzz.rule(move |n| n.to_string().contains(&digits), value)
+                }
+                _ => match key.parse::<i64>() {
+                    Ok(0) => return Err(error("divisor can't be 0".to_string())),
+                    Ok(divisor) => fizz_buzz.divisor(divisor, value),
+                    Err(_) => return Err(error(format!("unknown matcher '{}'", key))),
+                },
+            };
+        }
+        Ok(fizz_buzz)
+    }
+}
+
+// 1..16, 1..=15 or 1..
+fn parse_range(fizz_buzz: FizzBuzz, value: &str) -> Result<FizzBuzz, String> {
+    let invalid = || format!("invalid range '{}', expected start..end", value);
+    let (start, end) = value.split_once("..").ok_or_else(invalid)?;
+    let start: i64 = start.trim().parse().map_err(|_| invalid())?;
+    let end = end.trim();
+    if end.is_empty() {
+        return Ok(fizz_buzz.range(start..));
+    }
+    match end.strip_prefix('=') {
+        Some(end) => Ok(fizz_buzz.range(start..=end.trim().parse().map_err(|_| invalid())?)),
+        None => Ok(fizz_buzz.range(start..end.parse().map_err(|_| invalid())?)),
+    }
+}
+
+fn is_prime(n: i64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic() {
+        let words: Vec<String> = FizzBuzz::classic().range(1..16).iter().collect();
+        assert_eq!(
+            words.join(" "),
+            "1 2 Fizz 4 Buzz Fizz 7 8 Fizz Buzz 11 Fizz 13 14 FizzBuzz"
+        );
+    }
+
+    #[test]
+    fn rules_are_joined_in_order() {
+        let fizz_buzz = FizzBuzz::new()
+            .divisor(5, "Buzz")
+            .divisor(3, "Fizz")
+            .rule(|n| n < 0, "Negative");
+        assert_eq!(fizz_buzz.word(15), "BuzzFizz");
+        assert_eq!(fizz_buzz.word(-3), "FizzNegative");
+        assert_eq!(fizz_buzz.word(0), "BuzzFizz");
+        assert_eq!(fizz_buzz.word(7), "7");
+        assert_eq!(FizzBuzz::new().divisor(0, "Never").word(0), "0");
+    }
+
+    #[test]
+    fn is_lazy() {
+        let fizz_buzz = FizzBuzz::classic().range(1..);
+        let words: Vec<String> = fizz_buzz.iter().skip(29).take(2).collect();
+        assert_eq!(words, vec!["FizzBuzz", "31"]);
+
+        let end = FizzBuzz::classic().range(i64::MAX - 1..);
+        assert_eq!(end.iter().count(), 2);
+        assert_eq!(FizzBuzz::classic().range(5..5).iter().count(), 0);
+        assert_eq!(FizzBuzz::classic().range(5..=5).iter().count(), 1);
+    }
+
+    #[test]
+    fn loads_rules() {
+        let fizz_buzz = FizzBuzz::from_path("./data/fizzbuzz.txt").unwrap();
+        assert_eq!(fizz_buzz.rules().len(), 3);
+        assert_eq!(fizz_buzz.iter().last().unwrap(), "FizzBazz");
+
+        let fizz_buzz: FizzBuzz = "
+            range = 10..=13
+            prime = Prime
+            contains 1 = One
+        "
+        .parse()
+        .unwrap();
+        let words: Vec<String> = fizz_buzz.iter().collect();
+        assert_eq!(words, vec!["One", "PrimeOne", "One", "PrimeOne"]);
+    }
+
+    #[test]
+    fn reports_bad_rules() {
+        let error = "3 = Fizz\nfive = Buzz".parse::<FizzBuzz>().unwrap_err();
+        assert_eq!(error.to_string(), "line 2: unknown matcher 'five'");
+        let error = "range = 1-10".parse::<FizzBuzz>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1: invalid range '1-10', expected start..end"
+        );
+        assert!("0 = Zero".parse::<FizzBuzz>().is_err());
+        assert!("3 =".parse::<FizzBuzz>().is_err());
+        assert!("contains x = X".parse::<FizzBuzz>().is_err());
+        assert!(matches!(
+            FizzBuzz::from_path("./data/missing.txt"),
+            Err(FizzBuzzError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+// Python generators, `co.yield_(x).await` inside an async block plays the part of `yield x`
+//
+// def count_to(end):                    Generator::new(move |co| async move {
+//     count = 0                             let mut count = 0;
+//     while count < end:                    while count < end {
+//         count += 1                            count += 1;
+//         yield count                           co.yield_(count).await;
+//                                           }
+//                                       })
+//
+// The compiler turns the async block into the same kind of state machine Counter writes by
+// hand, `next()` polls it until it stops at the next yield. Nothing real is awaited, so no
+// runtime is needed, a no-op waker is enough
+
+use std::cell::Cell;
+use std::future::Future;
+use std::iter::FusedIterator;
+use std::pin::Pin;
+use std::rc::Rc;
+use std::task::{Context, Poll, Waker};
+
+/// Handed to the generator body, used to yield values
+pub struct Co<T> {
+    slot: Rc<Cell<Option<T>>>,
+}
+
+impl<T> Co<T> {
+    /// `yield value`, has to be awaited right away
+    pub fn yield_(&self, value: T) -> YieldNow<'_, T> {
+        YieldNow {
+            co: self,
+            value: Some(value),
+        }
+    }
+
+    /// `yield from iterable`
+    pub async fn yield_from(&self, iterable: impl IntoIterator<Item = T>) {
+        for value in iterable {
+            self.yield_(value).await;
+        }
+    }
+}
+
+/// Pending once to hand the value to `next()`, ready when the generator is resumed
+pub struct YieldNow<'a, T> {
+    co: &'a Co<T>,
+    value: Option<T>,
+}
+
+// the value is moved out and never pinned, so the future can move freely
+impl<T> Unpin for YieldNow<'_, T> {}
+
+impl<T> Future for YieldNow<'_, T> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<()> {
+        match self.value.take() {
+            Some(value) => {
+                self.co.slot.set(Some(value));
+                Poll::Pending
+            }
+            None => Poll::Ready(()),
+        }
+    }
+}
+
+pub struct Generator<'a, T> {
+    slot: Rc<Cell<Option<T>>>,
+    body: Pin<Box<dyn Future<Output = ()> + 'a>>,
+    done: bool,
+}
+
+impl<'a, T: 'a> Generator<'a, T> {
+    /// Nothing in `body` runs until the first `next()`, like calling a generator function
+    pub fn new<F, Fut>(body: F) -> Self
+    where
+        F: FnOnce(Co<T>) -> Fut,
+        Fut: Future<Output = ()> + 'a,
+    {
+        let slot = Rc::new(Cell::new(None));
+        let co = Co {
+            slot: Rc::clone(&slot),
+        };
+        Generator {
+            slot,
+            body: Box::pin(body(co)),
+            done: false,
+        }
+    }
+}
+
+impl<T> Iterator for Generator<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut context = Context::from_waker(Waker::noop());
+        match self.body.as_mut().poll(&mut context) {
+            // the body returned, Python raises StopIteration here
+            Poll::Ready(()) => {
+                self.done = true;
+                None
+            }
+            Poll::Pending => Some(
+                self.slot
+                    .take()
+                    .expect("a generator can only await `yield_`, nothing else wakes it up"),
+            ),
+        }
+    }
+}
+
+impl<T> FusedIterator for Generator<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runs_lazily_up_to_each_yield() {
+        let log = &Cell::new(0);
+        let mut generator = Generator::new(|co| async move {
+            log.set(1);
+            co.yield_("a").await;
+            log.set(2);
+            co.yield_from(["b", "c"]).await;
+            log.set(3);
+        });
+        assert_eq!(log.get(), 0);
+        assert_eq!(generator.next(), Some("a"));
+        assert_eq!(log.get(), 1);
+        assert_eq!(generator.by_ref().collect::<Vec<_>>(), vec!["b", "c"]);
+        assert_eq!(log.get(), 3);
+        assert_eq!(generator.next(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "can only await `yield_`")]
+    fn awaiting_anything_else_panics() {
+        let mut generator = Generator::new(|_: Co<i32>| std::future::pending());
+        generator.next();
+    }
+}
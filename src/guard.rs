@@ -0,0 +1,221 @@
+// RAII guards, what Rust has instead of Python's `try/finally` and `with`
+//
+// defer! { cleanup() }                                  // try: ... finally: cleanup()
+// guard::with(Connection::new("db"), |conn| query(conn))  // with connect("db") as conn: ...
+//
+// Both run their cleanup in `Drop`, so it happens on early returns, `?` and panics too.
+// DropRecorder writes down what was dropped in which order, tests assert on it instead of
+// scraping stdout
+
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// `defer! { ... }` runs the block when the current scope ends, later defers run first
+#[macro_export]
+macro_rules! defer {
+    ($($body:tt)*) => {
+        let _guard = $crate::guard::ScopeGuard::new(|| {
+            $($body)*
+        });
+    };
+}
+
+/// Runs `f` when dropped, unless it was dismissed
+pub struct ScopeGuard<F: FnOnce()> {
+    f: Option<F>,
+}
+
+impl<F: FnOnce()> ScopeGuard<F> {
+    pub fn new(f: F) -> Self {
+        ScopeGuard { f: Some(f) }
+    }
+
+    /// Drops the guard without running `f`, i.e. after a commit there's nothing to roll back
+    pub fn dismiss(mut self) {
+        self.f = None;
+    }
+}
+
+impl<F: FnOnce()> Drop for ScopeGuard<F> {
+    fn drop(&mut self) {
+        if let Some(f) = self.f.take() {
+            f();
+        }
+    }
+}
+
+/// How the body of a `with` ended, Python passes the same as `exc_type, exc, tb`
+#[derive(Debug)]
+pub enum Exit<'a> {
+    Ok,
+    Error(&'a dyn Error),
+    Panic,
+}
+
+impl fmt::Display for Exit<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Exit::Ok => write!(f, "ok"),
+            Exit::Error(e) => write!(f, "error: {}", e),
+            Exit::Panic => write!(f, "panic"),
+        }
+    }
+}
+
+/// Python's `__enter__` and `__exit__`
+pub trait ContextManager {
+    /// What `as` binds to
+    type Value;
+
+    fn enter(&mut self) -> Self::Value;
+
+    /// Called however the body ended, unlike Python it can't swallow the error
+    fn exit(&mut self, exit: Exit<'_>);
+}
+
+/// `with manager as value: body(value)`, errors are passed through after `exit`
+pub fn with<C, R, E>(mut manager: C, body: impl FnOnce(C::Value) -> Result<R, E>) -> Result<R, E>
+where
+    C: ContextManager,
+    E: Error,
+{
+    let value = manager.enter();
+    let result = {
+        let _on_panic = ExitOnPanic(&mut manager);
+        body(value)
+    };
+    match &result {
+        Ok(_) => manager.exit(Exit::Ok),
+        Err(e) => manager.exit(Exit::Error(e)),
+    }
+    result
+}
+
+struct ExitOnPanic<'a, C: ContextManager>(&'a mut C);
+
+impl<C: ContextManager> Drop for ExitOnPanic<'_, C> {
+    fn drop(&mut self) {
+        if std::thread::panicking() {
+            self.0.exit(Exit::Panic);
+        }
+    }
+}
+
+/// Shared log of drops and other events, clones write to the same log
+#[derive(Debug, Clone, Default)]
+pub struct DropRecorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl DropRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, event: impl Into<String>) {
+        self.lock().push(event.into());
+    }
+
+    /// A value that records `label` when it's dropped
+    pub fn track(&self, label: impl Into<String>) -> Tracked {
+        Tracked {
+            label: label.into(),
+            recorder: self.clone(),
+        }
+    }
+
+    pub fn events(&self) -> Vec<String> {
+        self.lock().clone()
+    }
+
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    // a panic while recording doesn't make the events wrong, so poisoning is ignored
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.events.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[derive(Debug)]
+pub struct Tracked {
+    label: String,
+    recorder: DropRecorder,
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.recorder.record(std::mem::take(&mut self.label));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::ParseIntError;
+
+    #[test]
+    fn drops_in_reverse_order() {
+        let recorder = DropRecorder::new();
+        {
+            let _a = recorder.track("a");
+            defer! { recorder.record("deferred"); }
+            let moved = recorder.track("moved");
+            let _b = recorder.track("b");
+            drop(moved);
+            ScopeGuard::new(|| recorder.record("dismissed")).dismiss();
+        }
+        assert_eq!(recorder.events(), vec!["moved", "b", "deferred", "a"]);
+    }
+
+    struct Resource {
+        log: DropRecorder,
+    }
+
+    impl ContextManager for Resource {
+        type Value = DropRecorder;
+
+        fn enter(&mut self) -> DropRecorder {
+            self.log.record("enter");
+            self.log.clone()
+        }
+
+        fn exit(&mut self, exit: Exit<'_>) {
+            self.log.record(format!("exit {}", exit));
+        }
+    }
+
+    #[test]
+    fn exit_runs_however_the_body_ends() {
+        let log = DropRecorder::new();
+        let resource = || Resource { log: log.clone() };
+
+        let ok: Result<i32, ParseIntError> = with(resource(), |log| {
+            log.record("body");
+            "42".parse()
+        });
+        assert_eq!(ok, Ok(42));
+        assert!(with(resource(), |_| "x".parse::<i32>()).is_err());
+        let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            with(resource(), |_| -> Result<(), ParseIntError> {
+                panic!("boom")
+            })
+        }));
+        assert!(panicked.is_err());
+
+        assert_eq!(
+            log.events(),
+            vec![
+                "enter",
+                "body",
+                "exit ok",
+                "enter",
+                "exit error: invalid digit found in string",
+                "enter",
+                "exit panic",
+            ]
+        );
+    }
+}
@@ -0,0 +1,691 @@
+// Python's `itertools` as an extension trait, every iterator gets these methods
+//
+// use python2rust::itertools::IterTools;
+// "AAABBC".chars().groupby(|&c| c)        // groupby("AAABBC")
+// [1, 2, 3].into_iter().accumulate(..)    // accumulate([1, 2, 3])
+//
+// Python returns tuples of any length, the combinatoric ones return `Vec`s here instead
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::iter::{Flatten, Peekable, RepeatN, Skip, StepBy, Take};
+use std::rc::Rc;
+
+pub trait IterTools: Iterator + Sized {
+    /// `chain.from_iterable(iterables)`
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(chain.from_iterable(["ABC", "DEF"])) == ['A', 'B', 'C', 'D', 'E', 'F']
+    /// let letters: String = ["ABC", "DEF"].iter().map(|s| s.chars()).chain_from().collect();
+    /// assert_eq!(letters, "ABCDEF");
+    /// ```
+    fn chain_from(self) -> Flatten<Self>
+    where
+        Self::Item: IntoIterator,
+    {
+        self.flatten()
+    }
+
+    /// `chain.from_iterable(repeat(iterable, n))`, `cycle` that stops after `n` rounds
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(islice(cycle("AB"), 6)) == ['A', 'B', 'A', 'B', 'A', 'B']
+    /// let letters: String = "AB".chars().cycle_n(3).collect();
+    /// assert_eq!(letters, "ABABAB");
+    /// ```
+    fn cycle_n(self, n: usize) -> Flatten<RepeatN<Self>>
+    where
+        Self: Clone,
+    {
+        std::iter::repeat_n(self, n).flatten()
+    }
+
+    /// `groupby(iterable, key)`, only consecutive items with the same key are grouped
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // [(k, "".join(g)) for k, g in groupby("AAAABBBCCDAABBB")]
+    /// // == [('A', 'AAAA'), ('B', 'BBB'), ('C', 'CC'), ('D', 'D'), ('A', 'AA'), ('B', 'BBB')]
+    /// let groups: Vec<(char, String)> = "AAAABBBCCDAABBB"
+    ///     .chars()
+    ///     .groupby(|&c| c)
+    ///     .map(|(key, group)| (key, group.into_iter().collect()))
+    ///     .collect();
+    /// assert_eq!(groups[..3], [('A', "AAAA".into()), ('B', "BBB".into()), ('C', "CC".into())]);
+    /// assert_eq!(groups.len(), 6);
+    ///
+    /// // [(k, list(g)) for k, g in groupby([1, 3, 2, 4, 5], key=lambda x: x % 2)]
+    /// // == [(1, [1, 3]), (0, [2, 4]), (1, [5])]
+    /// let groups: Vec<_> = [1, 3, 2, 4, 5].into_iter().groupby(|x| x % 2).collect();
+    /// assert_eq!(groups, vec![(1, vec![1, 3]), (0, vec![2, 4]), (1, vec![5])]);
+    /// ```
+    fn groupby<K, F>(self, key: F) -> GroupBy<Self, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        GroupBy {
+            iter: self.peekable(),
+            key,
+        }
+    }
+
+    /// `accumulate(iterable, func)`, like `fold` but yields every intermediate value
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(accumulate([1, 2, 3, 4, 5])) == [1, 3, 6, 10, 15]
+    /// let sums: Vec<i32> = (1..=5).accumulate(|total, x| total + x).collect();
+    /// assert_eq!(sums, vec![1, 3, 6, 10, 15]);
+    ///
+    /// // list(accumulate([3, 4, 6, 2, 1, 9, 0, 7, 5, 8], max))
+    /// // == [3, 4, 6, 6, 6, 9, 9, 9, 9, 9]
+    /// let maxes: Vec<i32> = [3, 4, 6, 2, 1, 9, 0, 7, 5, 8]
+    ///     .into_iter()
+    ///     .accumulate(|&max, x| max.max(x))
+    ///     .collect();
+    /// assert_eq!(maxes, vec![3, 4, 6, 6, 6, 9, 9, 9, 9, 9]);
+    /// ```
+    fn accumulate<F>(self, func: F) -> Accumulate<Self, F>
+    where
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, Self::Item) -> Self::Item,
+    {
+        Accumulate {
+            iter: self,
+            total: None,
+            func,
+        }
+    }
+
+    /// `pairwise(iterable)`, every overlapping pair
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(pairwise("ABCD")) == [('A', 'B'), ('B', 'C'), ('C', 'D')]
+    /// let pairs: Vec<_> = "ABCD".chars().pairwise().collect();
+    /// assert_eq!(pairs, vec![('A', 'B'), ('B', 'C'), ('C', 'D')]);
+    /// assert_eq!("A".chars().pairwise().next(), None);
+    /// ```
+    fn pairwise(self) -> Pairwise<Self>
+    where
+        Self::Item: Clone,
+    {
+        Pairwise {
+            iter: self,
+            previous: None,
+        }
+    }
+
+    /// `batched(iterable, n)`, the last batch can be shorter, panics when `n` is 0
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(batched("ABCDEFG", 3)) == [('A', 'B', 'C'), ('D', 'E', 'F'), ('G',)]
+    /// let batches: Vec<String> = "ABCDEFG"
+    ///     .chars()
+    ///     .batched(3)
+    ///     .map(|batch| batch.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(batches, vec!["ABC", "DEF", "G"]);
+    /// ```
+    fn batched(self, n: usize) -> Batched<Self> {
+        assert!(n > 0, "n must be at least one");
+        Batched { iter: self, n }
+    }
+
+    /// `product(a, b)`, every pair with one item from each side, `Iterator::product` already
+    /// multiplies numbers so it's named like the itertools crate
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // list(product("AB", "xy")) == [('A', 'x'), ('A', 'y'), ('B', 'x'), ('B', 'y')]
+    /// let pairs: Vec<_> = "AB".chars().cartesian_product("xy".chars()).collect();
+    /// assert_eq!(pairs, vec![('A', 'x'), ('A', 'y'), ('B', 'x'), ('B', 'y')]);
+    /// ```
+    fn cartesian_product<J>(self, other: J) -> Product<Self, J::IntoIter>
+    where
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        let other = other.into_iter();
+        Product {
+            iter: self,
+            current: None,
+            rest: other.clone(),
+            other,
+        }
+    }
+
+    /// `permutations(iterable, r)`, in the same order as Python
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // ["".join(p) for p in permutations("ABC", 2)]
+    /// // == ['AB', 'AC', 'BA', 'BC', 'CA', 'CB']
+    /// let words: Vec<String> = "ABC"
+    ///     .chars()
+    ///     .permutations(2)
+    ///     .map(|p| p.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(words, vec!["AB", "AC", "BA", "BC", "CA", "CB"]);
+    ///
+    /// // list(permutations(range(3))) has 6 items, permutations(range(3), 4) is empty
+    /// assert_eq!((0..3).permutations(3).count(), 6);
+    /// assert_eq!((0..3).permutations(4).count(), 0);
+    /// ```
+    fn permutations(self, r: usize) -> Permutations<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        let pool: Vec<_> = self.collect();
+        let n = pool.len();
+        Permutations {
+            indices: (0..n).collect(),
+            cycles: (n.saturating_sub(r) + 1..=n).rev().collect(),
+            state: match r <= n {
+                true => State::First,
+                false => State::Done,
+            },
+            pool,
+            r,
+        }
+    }
+
+    /// `combinations(iterable, r)`, sorted by position in the input
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // ["".join(c) for c in combinations("ABCD", 2)]
+    /// // == ['AB', 'AC', 'AD', 'BC', 'BD', 'CD']
+    /// let words: Vec<String> = "ABCD"
+    ///     .chars()
+    ///     .combinations(2)
+    ///     .map(|c| c.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(words, vec!["AB", "AC", "AD", "BC", "BD", "CD"]);
+    ///
+    /// // list(combinations(range(4), 3))
+    /// // == [(0, 1, 2), (0, 1, 3), (0, 2, 3), (1, 2, 3)]
+    /// let triples: Vec<_> = (0..4).combinations(3).collect();
+    /// assert_eq!(triples, vec![[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]);
+    /// ```
+    fn combinations(self, r: usize) -> Combinations<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        Combinations::new(self.collect(), r, false)
+    }
+
+    /// `combinations_with_replacement(iterable, r)`, items can repeat
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // ["".join(c) for c in combinations_with_replacement("ABC", 2)]
+    /// // == ['AA', 'AB', 'AC', 'BB', 'BC', 'CC']
+    /// let words: Vec<String> = "ABC"
+    ///     .chars()
+    ///     .combinations_with_replacement(2)
+    ///     .map(|c| c.into_iter().collect())
+    ///     .collect();
+    /// assert_eq!(words, vec!["AA", "AB", "AC", "BB", "BC", "CC"]);
+    /// ```
+    fn combinations_with_replacement(self, r: usize) -> Combinations<Self::Item>
+    where
+        Self::Item: Clone,
+    {
+        Combinations::new(self.collect(), r, true)
+    }
+
+    /// `islice(iterable, start, stop, step)`, None for `stop` goes to the end, panics when
+    /// `step` is 0
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // "".join(islice("ABCDEFG", 2, None)) == 'CDEFG'
+    /// // "".join(islice("ABCDEFG", 0, None, 2)) == 'ACEG'
+    /// // "".join(islice("ABCDEFG", 2, 4)) == 'CD'
+    /// assert_eq!("ABCDEFG".chars().islice(2, None, 1).collect::<String>(), "CDEFG");
+    /// assert_eq!("ABCDEFG".chars().islice(0, None, 2).collect::<String>(), "ACEG");
+    /// assert_eq!("ABCDEFG".chars().islice(2, Some(4), 1).collect::<String>(), "CD");
+    /// assert_eq!("ABCDEFG".chars().islice(4, Some(2), 1).collect::<String>(), "");
+    /// ```
+    fn islice(self, start: usize, stop: Option<usize>, step: usize) -> StepBy<Take<Skip<Self>>> {
+        let len = stop.map_or(usize::MAX, |stop| stop.saturating_sub(start));
+        self.skip(start).take(len).step_by(step)
+    }
+
+    /// `tee(iterable, n)`, `n` independent iterators over the same items, buffers what
+    /// the slowest one hasn't seen yet
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // a, b = tee("ABC"); next(a); list(a), list(b) == (['B', 'C'], ['A', 'B', 'C'])
+    /// let mut tees = "ABC".chars().tee(2);
+    /// let mut b = tees.pop().unwrap();
+    /// let mut a = tees.pop().unwrap();
+    /// assert_eq!(a.next(), Some('A'));
+    /// assert_eq!(a.collect::<String>(), "BC");
+    /// assert_eq!(b.collect::<String>(), "ABC");
+    /// ```
+    fn tee(self, n: usize) -> Vec<Tee<Self>>
+    where
+        Self::Item: Clone,
+    {
+        let shared = Rc::new(RefCell::new(TeeBuffer {
+            iter: self,
+            queues: (0..n).map(|_| Some(VecDeque::new())).collect(),
+        }));
+        (0..n)
+            .map(|index| Tee {
+                shared: Rc::clone(&shared),
+                index,
+            })
+            .collect()
+    }
+
+    /// `zip_longest(a, b)`, runs until both sides are done, the shorter side gives `None`
+    ///
+    /// ```
+    /// use python2rust::itertools::IterTools;
+    ///
+    /// // ["".join(p) for p in zip_longest("ABCD", "xy", fillvalue="-")]
+    /// // == ['Ax', 'By', 'C-', 'D-']
+    /// let pairs: Vec<String> = "ABCD"
+    ///     .chars()
+    ///     .zip_longest("xy".chars())
+    ///     .map(|(a, b)| [a.unwrap_or('-'), b.unwrap_or('-')].iter().collect())
+    ///     .collect();
+    /// assert_eq!(pairs, vec!["Ax", "By", "C-", "D-"]);
+    /// ```
+    fn zip_longest<J: IntoIterator>(self, other: J) -> ZipLongest<Self, J::IntoIter> {
+        ZipLongest {
+            a: self.fuse(),
+            b: other.into_iter().fuse(),
+        }
+    }
+}
+
+impl<I: Iterator> IterTools for I {}
+
+pub struct GroupBy<I: Iterator, F> {
+    iter: Peekable<I>,
+    key: F,
+}
+
+impl<I, K, F> Iterator for GroupBy<I, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let key = (self.key)(&first);
+        let mut group = vec![first];
+        while let Some(item) = self.iter.next_if(|item| (self.key)(item) == key) {
+            group.push(item);
+        }
+        Some((key, group))
+    }
+}
+
+pub struct Accumulate<I: Iterator, F> {
+    iter: I,
+    total: Option<I::Item>,
+    func: F,
+}
+
+impl<I, F> Iterator for Accumulate<I, F>
+where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let total = match self.total.take() {
+            Some(total) => (self.func)(&total, item),
+            None => item,
+        };
+        self.total = Some(total.clone());
+        Some(total)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub struct Pairwise<I: Iterator> {
+    iter: I,
+    previous: Option<I::Item>,
+}
+
+impl<I> Iterator for Pairwise<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let previous = match self.previous.take() {
+            Some(previous) => previous,
+            None => self.iter.next()?,
+        };
+        let next = self.iter.next()?;
+        self.previous = Some(next.clone());
+        Some((previous, next))
+    }
+}
+
+pub struct Batched<I> {
+    iter: I,
+    n: usize,
+}
+
+impl<I: Iterator> Iterator for Batched<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let batch: Vec<_> = self.iter.by_ref().take(self.n).collect();
+        match batch.is_empty() {
+            true => None,
+            false => Some(batch),
+        }
+    }
+}
+
+pub struct Product<I: Iterator, J> {
+    iter: I,
+    current: Option<I::Item>,
+    // `rest` is what's left of `other` for the current item
+    rest: J,
+    other: J,
+}
+
+impl<I, J> Iterator for Product<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.is_none() {
+            self.current = Some(self.iter.next()?);
+        }
+        match self.rest.next() {
+            Some(b) => Some((self.current.clone()?, b)),
+            None => {
+                self.current = Some(self.iter.next()?);
+                self.rest = self.other.clone();
+                let b = self.rest.next()?;
+                Some((self.current.clone()?, b))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    First,
+    Running,
+    Done,
+}
+
+/// The index juggling is the same as the pure Python version in the `itertools` docs
+pub struct Permutations<T> {
+    pool: Vec<T>,
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    r: usize,
+    state: State,
+}
+
+impl<T: Clone> Permutations<T> {
+    fn current(&self) -> Vec<T> {
+        self.indices[..self.r]
+            .iter()
+            .map(|&i| self.pool[i].clone())
+            .collect()
+    }
+
+    fn advance(&mut self) -> bool {
+        let n = self.pool.len();
+        for i in (0..self.r).rev() {
+            self.cycles[i] -= 1;
+            if self.cycles[i] == 0 {
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = self.cycles[i];
+                self.indices.swap(i, n - j);
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let more = match self.state {
+            State::First => true,
+            State::Running => self.advance(),
+            State::Done => false,
+        };
+        if !more {
+            self.state = State::Done;
+            return None;
+        }
+        self.state = State::Running;
+        Some(self.current())
+    }
+}
+
+/// Also the pure Python versions from the `itertools` docs
+pub struct Combinations<T> {
+    pool: Vec<T>,
+    indices: Vec<usize>,
+    with_replacement: bool,
+    state: State,
+}
+
+impl<T: Clone> Combinations<T> {
+    fn new(pool: Vec<T>, r: usize, with_replacement: bool) -> Self {
+        let possible = match with_replacement {
+            true => !pool.is_empty() || r == 0,
+            false => r <= pool.len(),
+        };
+        Combinations {
+            indices: match with_replacement {
+                true => vec![0; r],
+                false => (0..r).collect(),
+            },
+            pool,
+            with_replacement,
+            state: match possible {
+                true => State::First,
+                false => State::Done,
+            },
+        }
+    }
+
+    fn advance(&mut self) -> bool {
+        let (n, r) = (self.pool.len(), self.indices.len());
+        // the rightmost index that can still move
+        let last = |i: usize| match self.with_replacement {
+            true => n - 1,
+            false => i + n - r,
+        };
+        let Some(i) = (0..r).rev().find(|&i| self.indices[i] != last(i)) else {
+            return false;
+        };
+        self.indices[i] += 1;
+        for j in i + 1..r {
+            self.indices[j] = match self.with_replacement {
+                true => self.indices[i],
+                false => self.indices[j - 1] + 1,
+            };
+        }
+        true
+    }
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let more = match self.state {
+            State::First => true,
+            State::Running => self.advance(),
+            State::Done => false,
+        };
+        if !more {
+            self.state = State::Done;
+            return None;
+        }
+        self.state = State::Running;
+        Some(self.indices.iter().map(|&i| self.pool[i].clone()).collect())
+    }
+}
+
+struct TeeBuffer<I: Iterator> {
+    iter: I,
+    // items every tee hasn't seen yet, None once the tee was dropped
+    queues: Vec<Option<VecDeque<I::Item>>>,
+}
+
+pub struct Tee<I: Iterator> {
+    shared: Rc<RefCell<TeeBuffer<I>>>,
+    index: usize,
+}
+
+impl<I> Iterator for Tee<I>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut shared = self.shared.borrow_mut();
+        if let Some(item) = shared.queues[self.index].as_mut()?.pop_front() {
+            return Some(item);
+        }
+        let item = shared.iter.next()?;
+        for (index, queue) in shared.queues.iter_mut().enumerate() {
+            match queue {
+                Some(queue) if index != self.index => queue.push_back(item.clone()),
+                _ => {}
+            }
+        }
+        Some(item)
+    }
+}
+
+// nobody reads a dropped tee's queue anymore, so it stops buffering
+impl<I: Iterator> Drop for Tee<I> {
+    fn drop(&mut self) {
+        self.shared.borrow_mut().queues[self.index] = None;
+    }
+}
+
+pub struct ZipLongest<A, B> {
+    a: std::iter::Fuse<A>,
+    b: std::iter::Fuse<B>,
+}
+
+impl<A: Iterator, B: Iterator> Iterator for ZipLongest<A, B> {
+    type Item = (Option<A::Item>, Option<B::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (None, None) => None,
+            pair => Some(pair),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // expected values are from Python 3.11
+    #[test]
+    fn combinatorics_edge_cases() {
+        // list(permutations("", 0)) == [()]
+        assert_eq!("".chars().permutations(0).count(), 1);
+        assert_eq!((0..4).permutations(2).count(), 12);
+        // the last of permutations(range(3)) is (2, 1, 0)
+        assert_eq!((0..3).permutations(3).last(), Some(vec![2, 1, 0]));
+        // list(combinations("AB", 3)) == []
+        assert_eq!("AB".chars().combinations(3).count(), 0);
+        assert_eq!(
+            "AB".chars().combinations(0).collect::<Vec<_>>(),
+            vec![vec![]]
+        );
+        // list(combinations_with_replacement("", 2)) == []
+        assert_eq!("".chars().combinations_with_replacement(2).count(), 0);
+        assert_eq!((0..3).combinations_with_replacement(3).count(), 10);
+    }
+
+    #[test]
+    fn tee_buffers_for_the_slower_iterator() {
+        let mut tees = (1..=3).tee(3);
+        let all: Vec<Vec<i32>> = tees.iter_mut().map(|tee| tee.collect()).collect();
+        assert_eq!(all, vec![vec![1, 2, 3]; 3]);
+        assert!(tees[0]
+            .shared
+            .borrow()
+            .queues
+            .iter()
+            .all(|queue| queue.as_ref().is_some_and(VecDeque::is_empty)));
+    }
+
+    #[test]
+    fn dropped_tees_stop_buffering() {
+        let mut tees = (0..1000).tee(3);
+        let dropped = tees.remove(1);
+        drop(dropped);
+        assert_eq!(tees[0].by_ref().count(), 1000);
+        let shared = tees[0].shared.borrow();
+        let lens: Vec<Option<usize>> = shared
+            .queues
+            .iter()
+            .map(|q| q.as_ref().map(VecDeque::len))
+            .collect();
+        assert_eq!(lens, vec![Some(0), None, Some(1000)]);
+    }
+
+    #[test]
+    fn product_with_empty_side() {
+        assert_eq!((0..3).cartesian_product(0..0).count(), 0);
+        assert_eq!((0..0).cartesian_product(0..3).count(), 0);
+        assert_eq!((0..2).cartesian_product(0..3).count(), 6);
+    }
+}
@@ -0,0 +1,125 @@
+// Registry of all chapters, so they can be listed and run one at a time
+// instead of scrolling through the output of every chapter at once.
+
+pub struct Lesson {
+    pub id: &'static str,
+    pub title: &'static str,
+    pub run: fn(),
+    /// Python version of the lesson, see `compare`
+    pub python: &'static str,
+}
+
+pub const LESSONS: &[Lesson] = &[
+    Lesson {
+        id: "01",
+        title: "Basics and arrays",
+        run: crate::v01_basics,
+        python: include_str!("../python/v01_basics.py"),
+    },
+    Lesson {
+        id: "02",
+        title: "Vectors",
+        run: crate::v02_vectors,
+        python: include_str!("../python/v02_vectors.py"),
+    },
+    Lesson {
+        id: "03",
+        title: "Strings",
+        run: crate::v03_strings,
+        python: include_str!("../python/v03_strings.py"),
+    },
+    Lesson {
+        id: "04",
+        title: "Functions and closures",
+        run: crate::v04_functions_closures,
+        python: include_str!("../python/v04_functions_closures.py"),
+    },
+    Lesson {
+        id: "05",
+        title: "FizzBuzz",
+        run: crate::v05_fizzbuzz,
+        python: include_str!("../python/v05_fizzbuzz.py"),
+    },
+    Lesson {
+        id: "06",
+        title: "Pattern matching",
+        run: crate::v06_pattern_matching,
+        python: include_str!("../python/v06_pattern_matching.py"),
+    },
+    Lesson {
+        id: "07",
+        title: "Structs",
+        run: crate::v07_structs,
+        python: include_str!("../python/v07_structs.py"),
+    },
+    Lesson {
+        id: "08",
+        title: "HashMaps",
+        run: crate::v08_hashmaps,
+        python: include_str!("../python/v08_hashmaps.py"),
+    },
+    Lesson {
+        id: "09",
+        title: "Iterators",
+        run: crate::v09_iterators,
+        python: include_str!("../python/v09_iterators.py"),
+    },
+    Lesson {
+        id: "0a",
+        title: "Error handling",
+        run: crate::v0a_error_handling,
+        python: include_str!("../python/v0a_error_handling.py"),
+    },
+    Lesson {
+        id: "0b",
+        title: "Traits",
+        run: crate::v0b_traits,
+        python: include_str!("../python/v0b_traits.py"),
+    },
+    Lesson {
+        id: "0c",
+        title: "Operator overloading",
+        run: crate::v0c_operator_overloading,
+        python: include_str!("../python/v0c_operator_overloading.py"),
+    },
+    Lesson {
+        id: "0d",
+        title: "Files",
+        run: crate::v0d_files,
+        python: include_str!("../python/v0d_files.py"),
+    },
+];
+
+// accepts "0c", "0C" and "v0c"
+pub fn find(id: &str) -> Option<&'static Lesson> {
+    let id = id.to_ascii_lowercase();
+    let id = id.strip_prefix('v').unwrap_or(&id);
+    LESSONS.iter().find(|lesson| lesson.id == id)
+}
+
+pub fn run_all() {
+    for lesson in LESSONS {
+        (lesson.run)();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_ignores_case_and_prefix() {
+        assert_eq!(find("0c").map(|l| l.title), Some("Operator overloading"));
+        assert_eq!(find("0C").map(|l| l.title), Some("Operator overloading"));
+        assert_eq!(find("v0d").map(|l| l.title), Some("Files"));
+        assert!(find("0e").is_none());
+    }
+
+    #[test]
+    fn ids_are_unique() {
+        let mut ids: Vec<&str> = LESSONS.iter().map(|l| l.id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), LESSONS.len());
+    }
+}
@@ -0,0 +1,1363 @@
+pub mod animals;
+pub mod buckets;
+pub mod builder;
+pub mod case;
+pub mod collections;
+pub mod compare;
+pub mod counter;
+pub mod csv;
+pub mod dict;
+pub mod expr;
+pub mod fizzbuzz;
+pub mod generator;
+pub mod guard;
+pub mod itertools;
+pub mod lessons;
+pub mod matrix;
+pub mod pangram;
+mod python;
+pub mod report;
+pub mod sales;
+pub mod slicing;
+pub mod text;
+pub mod trace;
+pub mod utf8;
+
+use matrix::Matrix;
+
+// Start 01. Basics
+fn v01_basics() {
+    println!("Hello, world!");
+
+    let m = Matrix::from_rows(vec![vec![101.2_f32, 202.12]]).expect("a single row");
+    println!("{}", m);
+
+    // arrays
+    println!("### Arrays");
+
+    let xs = [1, 2, 3, 4, 5];
+    let ys: [i32; 500] = [0; 500];
+
+    println!("First {:?} {:?}", xs[0], ys[0]);
+    println!("Len: {:?} {:?}", xs.len(), ys.len());
+
+    println!("Slice xs: {:?}", &xs[1..3]);
+    println!("Slice ys: {:?}", &ys[1..3]);
+
+    // println!("Out of bound {:?}", xs[5]); // - fails to compile
+}
+// End 01. Basics
+
+// Start 02. Vectors
+fn v02_vectors() {
+    // vectors (lists in python)
+    println!("### Vectors");
+
+    let mut xs = vec![1_i32, 2, 3];
+    println!("Initial vector {:?}", xs);
+    xs.push(4);
+    println!("Added 4: {:?}", xs);
+    // panics - println!("Access 6th: {:?}", xs[5]);
+    println!("Slice: {:?}", &xs[1..3]);
+    println!("Pop last element {:?}", xs.pop());
+    for x in xs.iter() {
+        println!("> {}", x);
+    }
+
+    for (i, x) in xs.iter().enumerate() {
+        println!("Enumerate pos: {} val: {}", i, x);
+    }
+
+    for x in xs.iter_mut() {
+        *x *= 3;
+    }
+    println!("Multiplied vector: {:?}", xs);
+
+    // 0..10 <- creates an iterator, collect collects it
+    let collected_iter: Vec<i32> = (0..10).collect();
+    println!("Collected iterator: {:?}", collected_iter);
+}
+// End 02. Vectors
+
+// Start 03. Strings
+use slicing::Unit;
+
+fn v03_strings() {
+    // Strings
+    println!("### Strings");
+    // .chars
+    // crate: unicode_normalization
+    // utf-8
+    // Uses 1-4 bytes per character
+    // 7  bits - from U+0000 to U+007F   - 0xxx_xxxx
+    // 11 bits - from U+0080 to U+07FF   - 110x_xxxx 10xx_xxxx
+    // 16 bits - from U+0800 to U+FFFF   - 1110_xxxx 10xx_xxxx 10xx_xxxx
+    // 21 bits - from U+10000 to U+10FFFF - 1111_0xxx 10xx_xxxx 10xx_xxxx 10xx_xxxx
+    // 110x - two bytes are used, 1110 - three bytes are used, 1111_0 - 4 bytes are used
+    // A = hex(41), bin(0100_0001)
+    // Ä = hex(C3 84), bin(1100_0011 1000_0100)
+    // € = hex(E2 82 AC), bin(1110_0010 1000_0010 1010_1100)
+    // 𝄞 = hex(F0 9D 84 9E), bin(1111_0000 1001_1101 1000_0100 1001_1110)
+    // `cargo run -- utf8 <text>` prints this for any text, see utf8.rs
+
+    let alice = String::from("I like dogs");
+    let bob: String = alice.replace("dog", "cat");
+    println!("Alice says: {}", alice);
+    println!("Bob says: {}", bob);
+
+    let panagram: &'static str = "the quick brown fox jumps over the lazy";
+    println!("Panagram: {}", panagram);
+    // missing "dog"
+    println!("{}", pangram::analyze(panagram, pangram::LATIN));
+    let greek = "Ξεσκεπάζω την ψυχοφθόρα βδελυγμία";
+    println!("{}: {}", greek, pangram::analyze(greek, pangram::GREEK));
+
+    println!("Words in reverse");
+    for word in panagram.split_whitespace().rev() {
+        println!("> {}", word);
+    }
+
+    // graphemes rather than chars, so combining marks stay on their letter
+    let graphemes: Vec<String> = text::unique_graphemes(panagram);
+
+    let mut string = String::new();
+    for g in graphemes {
+        string.push_str(&g);
+        string.push_str(", ");
+    }
+    let chars_to_trim: &[char] = &[' ', ','];
+    let trimmed_str: &str = string.trim_matches(chars_to_trim);
+    println!("Used    chars: {}", string);
+    println!("Trimmed chars: {}", trimmed_str);
+
+    // the same text composed (ë) and decomposed (e + U+0308) is a different String
+    let composed = "no\u{eb}l";
+    let decomposed = "noe\u{308}l";
+    println!(
+        "{} == {}: {}, normalized: {}",
+        composed,
+        decomposed,
+        composed == decomposed,
+        text::eq_normalized(composed, decomposed)
+    );
+    println!("ﬁ == fi compatible: {}", text::eq_compatible("ﬁ", "fi"));
+    println!(
+        "{} reversed by char: {}, by grapheme: {}",
+        decomposed,
+        decomposed.chars().rev().collect::<String>(),
+        text::reverse(decomposed)
+    );
+
+    let names: Vec<String> = vec![
+        "oleg".to_string(),
+        "óleg".to_string(),
+        "¶πø^ä".to_string(),
+        "ǆungla".to_string(),
+        "ßa".to_string(),
+    ];
+    for name in names.iter() {
+        println!("{} -> {}", name, naive_capitalize(name));
+        println!("{} -> {}", name, ascii_capitalize(name));
+        // same as Python's str.capitalize(), uses titlecase: ǆ -> ǅ instead of Ǆ
+        println!("{} -> {}", name, case::capitalize(name));
+    }
+
+    let phrase = "they're bill's friends from the UK";
+    println!("title: {}", case::title(phrase));
+    println!("capwords: {}", case::capwords(phrase));
+    let identifier = "parseHTTPResponse code";
+    println!(
+        "{} -> {} {} {} {}",
+        identifier,
+        case::to_snake_case(identifier),
+        case::to_kebab_case(identifier),
+        case::to_camel_case(identifier),
+        case::to_pascal_case(identifier)
+    );
+
+    // UTF-8
+
+    let ascii = String::from("Hello");
+    println!("'{}': {}", ascii, slicing::sizes(&ascii));
+    println!("{:?}", ascii.as_bytes());
+    println!("after e: {:?}", &ascii[2..]);
+
+    let uni = String::from("Héllǒ");
+    println!("'{}': {}", uni, slicing::sizes(&uni));
+    println!("{:?}", uni.as_bytes());
+    // println!("after e: {:?}", &uni[2..]);  // throws error - byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3) of `Héllǒ`
+    // the inspector shows why, 'é' takes bytes 1 and 2
+    println!("{}", utf8::inspect(&uni));
+    if let Some(error) = utf8::slice_error(&uni, 2) {
+        println!("&uni[2..]: {}", error);
+    }
+    // Python style slicing, by char or grapheme, and by byte without the panic
+    for (expr, unit) in [
+        ("2:", Unit::Char),
+        ("::-1", Unit::Char),
+        ("-1:", Unit::Grapheme),
+        ("-2:", Unit::Byte),
+        ("2:", Unit::Byte),
+    ] {
+        let sliced = expr
+            .parse()
+            .and_then(|slice| slicing::slice(&uni, &slice, unit));
+        match sliced {
+            Ok(sliced) => println!("uni[{}] by {}: {}", expr, unit, sliced),
+            Err(error) => println!("uni[{}] by {}: error, {}", expr, unit, error),
+        }
+    }
+
+    // multiline strings
+    let more = "many
+    lines"
+        .to_string();
+    println!("{}", more);
+
+    let escape = " blah \"\"\" asd '
+newline \n
+\n
+test
+"
+    .to_string();
+    println!("{}", escape);
+
+    let single_char = 'a'; // 'ab' <- fails
+    println!("{}", single_char);
+}
+
+fn naive_capitalize(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        None => String::new(),
+        Some(f) => f.to_uppercase().to_string() + c.as_str(),
+    }
+}
+
+fn ascii_capitalize(s: &str) -> String {
+    let mut c = s.chars();
+    match c.next() {
+        None => String::new(),
+        Some(f) => f.to_ascii_uppercase().to_string() + c.as_str(),
+    }
+}
+// End 03. Strings
+
+// Start 04. Functions and closures
+fn v04_functions_closures() {
+    // functions
+
+    println!("{}", do_something(3));
+
+    // closures
+    let example_closure = |x| x;
+    let s = example_closure(String::from("Hello"));
+    // let n = example_closure(5); <- fails as closures are strongly typed
+    let n = example_closure(5.to_string()); // <- works
+
+    println!("{} {}", s, n);
+
+    let x = vec![1, 2, 3];
+    let equal_to_x = move |z| z == x;
+    let y = vec![1, 2, 3];
+    assert!(equal_to_x(y));
+
+    let items: Vec<i32> = vec![1, 2, 3, 4, 5];
+    let plus_one: Vec<_> = items.iter().map(|x| x + 1).collect();
+    let sum_all: i32 = items.iter().map(|x| x + 1).sum();
+    println!("{:?} {}", plus_one, sum_all);
+
+    let two_args = |x, y| x - y;
+    println!("{}", two_args(5, 3));
+}
+
+fn do_something(x: u32) -> u32 {
+    fn do_something_else(x: u32) -> u32 {
+        x * 3
+    }
+    do_something_else(x)
+}
+// End 04. Functions and closures
+
+use fizzbuzz::FizzBuzz;
+
+fn v05_fizzbuzz() {
+    // rules are checked in order, 15 matches both and becomes FizzBuzz
+    let fizz_buzz = FizzBuzz::classic().range(1..16);
+
+    println!("---- FizzBuzz Start ----");
+    for word in &fizz_buzz {
+        println!("{}", word);
+    }
+    println!("----");
+    fizz_buzz.iter().for_each(|word| println!("{}", word));
+
+    // same rules from a config file, plus 7 = Bazz
+    match FizzBuzz::from_path("./data/fizzbuzz.txt") {
+        Ok(from_file) => println!("{}", from_file.iter().collect::<Vec<_>>().join(" ")),
+        Err(e) => println!("Error: {}", e),
+    }
+    println!("---- FizzBuzz End ----");
+}
+
+use animals::{Animal, Classifier, Details};
+
+fn v06_pattern_matching() {
+    println!("---- Pattern Matching Start ----");
+
+    fn number(x: i32) {
+        match x {
+            1 => println!("one"),
+            2 | 3 => println!("two or three"),
+            4..=i32::MAX => println!("four or bigger"),
+            _ => println!("anything"),
+        }
+    }
+
+    for x in 0..6 {
+        number(x)
+    }
+    let fido = Animal::Dog(Details::new("Fido", 3, 12.5));
+    let tom = Animal::Cat(Details::new("Tom", 3, 4.2));
+    println!("{}", animals::classify(&fido));
+    println!("{}", animals::classify(&tom));
+
+    // the same match guards over real data
+    match animals::read_csv("./data/animals.csv") {
+        Ok(all) => {
+            for animal in &all {
+                let description = animals::classify(animal);
+                println!("{:<12} {}", description.label, description);
+            }
+        }
+        Err(e) => println!("Error: {}", e),
+    }
+    // custom rules go before the built-in ones
+    let classifier = Classifier::new().rule("heavy", |animal| animal.details().weight > 100.0);
+    match animals::read_json("./data/animals.json") {
+        Ok(all) => all
+            .iter()
+            .map(|animal| classifier.classify(animal))
+            .filter(|description| description.label == "heavy")
+            .for_each(|description| println!("{}", description)),
+        Err(e) => println!("Error: {}", e),
+    }
+
+    // the same rules as the FizzBuzz lesson, matched inside `FizzBuzz::word`
+    let rules = FizzBuzz::classic();
+    let fizz_buzz = |x| println!("{}", rules.word(x));
+
+    (1..24).for_each(fizz_buzz);
+
+    println!("---- Pattern Matching End ----");
+}
+
+use builder::{Missing, Set, ValidationError, Validator};
+
+// the structs lesson's D, out here so the tests can reach its builder
+mod v07 {
+    use crate::builder::{Missing, Set, ValidationError, Validator};
+
+    #[derive(Debug)]
+    #[allow(dead_code)] // fields are only shown through Debug
+    pub struct D {
+        pub x: i32,
+        pub y: i32,
+        pub z: i32,
+    }
+
+    impl D {
+        pub fn new(x: i32, y: i32, z: Option<i32>) -> Self {
+            match z {
+                Some(z) => Self { x, y, z },
+                None => Self { x, y, z: 0 },
+            }
+        }
+    }
+
+    use std::default::Default; // import trait
+
+    #[allow(clippy::derivable_impls)] // written out by hand to show the trait
+    impl Default for D {
+        fn default() -> Self {
+            Self { x: 0, y: 0, z: 0 }
+        }
+    }
+
+    // a builder enforces it at compile time, x and y are required and z is optional
+    // `build` only exists on DBuilder<Set<i32>, Set<i32>>
+    pub struct DBuilder<X, Y> {
+        x: X,
+        y: Y,
+        z: i32,
+    }
+
+    impl D {
+        pub fn builder() -> DBuilder<Missing, Missing> {
+            DBuilder {
+                x: Missing,
+                y: Missing,
+                z: 0,
+            }
+        }
+    }
+
+    impl<Y> DBuilder<Missing, Y> {
+        pub fn x(self, x: i32) -> DBuilder<Set<i32>, Y> {
+            DBuilder {
+                x: Set(x),
+                y: self.y,
+                z: self.z,
+            }
+        }
+    }
+
+    impl<X> DBuilder<X, Missing> {
+        pub fn y(self, y: i32) -> DBuilder<X, Set<i32>> {
+            DBuilder {
+                x: self.x,
+                y: Set(y),
+                z: self.z,
+            }
+        }
+    }
+
+    impl<X, Y> DBuilder<X, Y> {
+        pub fn z(self, z: i32) -> Self {
+            DBuilder { z, ..self }
+        }
+    }
+
+    impl DBuilder<Set<i32>, Set<i32>> {
+        pub fn build(self) -> Result<D, ValidationError> {
+            let d = D {
+                x: self.x.0,
+                y: self.y.0,
+                z: self.z,
+            };
+            Validator::new("D", d)
+                .rule(
+                    |d| d.x >= 0,
+                    |d| format!("x must not be negative, got {}", d.x),
+                )
+                .rule(
+                    |d| d.y >= 0,
+                    |d| format!("y must not be negative, got {}", d.y),
+                )
+                .rule(
+                    |d| d.z <= 100,
+                    |d| format!("z must be at most 100, got {}", d.z),
+                )
+                .finish()
+        }
+    }
+}
+
+fn v07_structs() {
+    println!("---- Structs Start ----");
+
+    struct A {
+        x: i32,
+        y: i32,
+    }
+
+    // struct B {
+    //     members: static Vec<i32>, <- doesn't exist in rust
+    // }
+
+    let mut a = A { x: 1, y: 2 };
+    a.x += 2;
+
+    let a_updated = A { y: 4, ..a };
+    println!("a.x {} a_updated.x {}", a.x, a_updated.x);
+    println!("a.y {} a_updated.y {}", a.y, a_updated.y);
+
+    use v07::D;
+
+    let d1 = D::new(1, 2, None);
+
+    // provides default option for all values, no way to enforce just 1 value
+    // if users have to provide some value, use `new` convention
+    let d2 = D {
+        x: 1,
+        y: 2,
+        ..D::default()
+    };
+
+    println!("{:?}", d1);
+    println!("{:?}", d2);
+
+    // D::builder() in `mod v07` enforces it at compile time
+    // D::builder().x(1).z(3).build() <- doesn't compile, y is missing
+    println!("{:?}", D::builder().x(1).y(2).z(3).build());
+    if let Err(e) = D::builder().x(-1).y(-2).z(300).build() {
+        println!("{}", e);
+    }
+
+    println!("---- Structs End ----");
+}
+
+#[macro_use]
+extern crate maplit;
+fn v08_hashmaps() {
+    println!("---- HashMaps Start ----");
+
+    use std::collections::HashMap;
+
+    let literal: HashMap<_, _> = vec![("key", "value"), ("blah", "blubb")]
+        .into_iter()
+        .collect();
+    println!("{:?}", literal["key"]);
+
+    let mut mutable = HashMap::new();
+    mutable.insert("one", 1);
+    mutable.insert("two", 2);
+    mutable.remove("one");
+    println!("{:?}", mutable.get("one"));
+    println!("{:?}", mutable.get("two"));
+
+    // HashMap iterates in a random order, sort to print the same thing every run
+    mutable.insert("three", 3);
+    let mut items: Vec<_> = mutable.iter().collect();
+    items.sort();
+    println!("{:?}", items);
+
+    let map = hashmap! {
+        "a" => 1,
+        "b" => 2,
+    };
+    println!("{}", map.len());
+
+    // Dict keeps the insertion order like Python's dict
+    use dict::Dict;
+
+    let literal: Dict<_, _> = vec![("key", "value"), ("blah", "blubb")]
+        .into_iter()
+        .collect();
+    println!("{:?}", literal);
+
+    let mut mutable = Dict::new();
+    mutable.insert("one", 1);
+    mutable.insert("two", 2);
+    mutable.pop("one");
+    println!("{:?}", mutable.get("one"));
+    println!("{:?}", mutable.get("two"));
+
+    mutable.insert("three", 3);
+
+    for (k, v) in &mutable {
+        println!("{}: {}", k, v);
+    }
+
+    let map = dict! {
+        "a" => 1,
+        "b" => 2,
+    };
+
+    println!("{:?}", map);
+
+    // Python's dict methods
+    println!("get with default: {}", mutable.get_or("four", &4));
+    *mutable.setdefault("four", 0) += 4;
+    mutable.update(dict! { "two" => 22, "five" => 5 });
+    println!("{:?}", mutable);
+    println!("{:?}", mutable.popitem());
+    println!("{:?}", mutable.pop("two"));
+    println!("{}", mutable);
+
+    // defaultdict and Counter
+    use collections::{DefaultMap, Tally};
+
+    let countries = |path| -> Result<Tally<String>> {
+        let sales = sales::read_sales(path)?;
+        Ok(sales.into_iter().map(|sale| sale.country).collect())
+    };
+    match (
+        countries("./data/sales.csv"),
+        countries("./data/sales_2023.csv"),
+    ) {
+        (Ok(earlier), Ok(later)) => {
+            println!("countries: {:?}", earlier);
+            println!("most common: {:?}", earlier.most_common(1));
+            println!("with 2023: {:?}", &earlier + &later);
+            println!("only 2023: {:?}", &later - &earlier);
+        }
+        (Err(e), _) | (_, Err(e)) => println!("Error: {}", e),
+    }
+
+    let mut amounts = DefaultMap::new(Vec::new);
+    for sale in sales::read_sales("./data/sales.csv").unwrap_or_default() {
+        amounts.entry(sale.country).push(sale.amount);
+    }
+    println!("amounts: {:?}", amounts);
+
+    println!("---- HashMaps End ----");
+}
+
+pub use counter::{Counter, Unbounded};
+use generator::Generator;
+
+/// Counter::new() as a generator, the `count` field becomes a local variable
+pub fn count_to(end: u32) -> Generator<'static, u32> {
+    Generator::new(move |co| async move {
+        let mut count = 0;
+        while count < end {
+            count += 1;
+            co.yield_(count).await;
+        }
+    })
+}
+
+/// Every Fibonacci number that fits in a u64
+pub fn fibonacci() -> Generator<'static, u64> {
+    Generator::new(|co| async move {
+        // `b` is None once it doesn't fit anymore
+        let (mut a, mut b) = (0u64, Some(1u64));
+        loop {
+            co.yield_(a).await;
+            let Some(next) = b else {
+                return;
+            };
+            (a, b) = (next, a.checked_add(next));
+        }
+    })
+}
+
+fn v09_iterators() {
+    println!("---- Iterators Start ----");
+    // more examples in the tests below, run them with `cargo test`
+    use itertools::IterTools;
+    use trace::Traced;
+
+    // Counter works like Python's range. Its Drop runs however the scope is left, even on a
+    // panic, and with `record_drop` it logs where it stopped, the traits lesson shows that
+    let mut countdown = Counter::range(10, 0, -3);
+    println!("len: {}", countdown.len());
+    println!(
+        "front: {:?}, back: {:?}",
+        countdown.next(),
+        countdown.next_back()
+    );
+    println!("middle: {:?}", countdown.by_ref().collect::<Vec<_>>());
+    countdown.reset();
+    println!("reversed: {:?}", countdown.rev().collect::<Vec<_>>());
+
+    // lazy: nothing runs until something asks for a value
+    let lazy = (1..=3).trace("lazy").map(|x| x * 10);
+    println!("built the chain, nothing traced yet");
+    println!("took: {:?}", lazy.take(2).collect::<Vec<_>>());
+
+    // eager: collect runs everything right away
+    let eager: Vec<i32> = (1..=3).trace("eager").collect();
+    println!("collected: {:?}", eager);
+
+    // iter borrows, iter_mut borrows mutably, into_iter takes ownership
+    let mut names = vec![String::from("ann"), String::from("bob")];
+    let lengths: Vec<usize> = names.iter().map(|name| name.len()).collect();
+    println!("iter: {:?} still has {:?}", lengths, names);
+    for name in names.iter_mut() {
+        *name = name.to_uppercase();
+    }
+    println!("iter_mut: {:?}", names);
+    let owned: Vec<String> = names.into_iter().map(|name| name + "!").collect();
+    println!("into_iter: {:?}", owned); // `names` is gone now
+
+    // every value goes through the whole chain before the next one starts
+    let total: i32 = Counter::range(1, 7, 1)
+        .trace("counter")
+        .filter(|x| x % 2 == 0)
+        .trace("filter")
+        .map(|x| x * x)
+        .trace("map")
+        .sum();
+    println!("sum of even squares: {}", total);
+
+    // same with the itertools adapters, take stops pulling after 3 values
+    let sums: Vec<i32> = Counter::range(1, 100, 1)
+        .trace("counter")
+        .accumulate(|total, x| total + x)
+        .take(3)
+        .collect();
+    println!("running sums: {:?}", sums);
+    let pairs: Vec<_> = "abc".chars().pairwise().collect();
+    println!("pairs: {:?}", pairs);
+
+    // generators keep their state in local variables instead of struct fields
+    println!("generated: {:?}", count_to(5).collect::<Vec<_>>());
+    println!("fibonacci: {:?}", fibonacci().take(10).collect::<Vec<_>>());
+    let first_big = fibonacci().trace("fibonacci").find(|&n| n > 20);
+    println!("first above 20: {:?}", first_big);
+    println!("---- Iterators End ----");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*; // test has to import what we define in the library
+
+    #[test]
+    fn calling_next_directly() {
+        let mut counter = Counter::new();
+        assert_eq!(counter.next(), Some(1));
+        assert_eq!(counter.next(), Some(2));
+        assert_eq!(counter.next(), Some(3));
+        assert_eq!(counter.next(), Some(4));
+        assert_eq!(counter.next(), Some(5));
+        assert_eq!(counter.next(), None);
+    }
+
+    #[test]
+    fn using_other_iterator_trait_methods() {
+        let sum: u32 = Counter::new()
+            .zip(Counter::new().skip(1))
+            .map(|(a, b)| a * b)
+            .filter(|x| x % 3 == 0)
+            .sum();
+        assert_eq!(18, sum);
+    }
+
+    #[test]
+    fn generators_match_the_hand_written_versions() {
+        assert!(count_to(5).eq(Counter::new()));
+        assert_eq!(fibonacci().nth(10), Some(55));
+        // fib(93) is the last one below u64::MAX
+        assert_eq!(fibonacci().count(), 94);
+    }
+}
+
+// Start error handling
+
+fn v0a_error_handling() {
+    println!("---- Error handling Start ----");
+    // rust has no exceptions
+    // use Result<T, E> to return value or error
+    // or Option<T> for optional values
+    // For libraries - https://crates.io/crates/thiserror
+    // For apps - `anyhow` or `eyre`
+
+    let food = Some("cabbage");
+    let snake = Some("snake");
+    let void = None;
+
+    give_commoner(food);
+    give_commoner(snake);
+    give_commoner(void);
+
+    let bird = Some("robin");
+    // let nothing: Option<std::option::Option<None>> = None;
+
+    give_royal(bird);
+    // give_royal(nothing); - throws an error on unwrap
+
+    // unpacking options with ? <- this can be nested in a line, i.e. op1?.op2?.value;
+
+    let age: Option<u8> = Some(32);
+    next_birthday(age);
+
+    let twenty = multiply("10", "2");
+    println!("Twenty: {:?}", twenty);
+    println!("t * 2: {:?}", multiply("t", "2")); // used to panic on unwrap
+    println!("i32::MAX * 2: {:?}", multiply("2147483647", "2")); // used to overflow
+
+    // both are thin wrappers over the expression evaluator
+    println!(
+        "10 * (2 + 3) / 4 = {:?} as i32, {:?} as f64",
+        expr::eval::<i32>("10 * (2 + 3) / 4"),
+        expr::eval::<f64>("10 * (2 + 3) / 4")
+    );
+    println!("1 / 0 = {:?}", expr::eval::<i32>("1 / 0"));
+
+    // error handling boilerplate
+    let numbers = vec!["42", "93", "18"];
+    let empty = vec![];
+    let strings = vec!["tofu", "93", "18"];
+    print(double_first(numbers));
+    print(double_first(empty));
+    print(double_first(strings).wrap_err("doubling the first of the strings"));
+
+    println!("---- Error handling End ----");
+}
+
+// error handling boilerplate for Rust
+use std::fmt;
+use std::fmt::Formatter;
+
+// Initial implementation, without custom errors
+// standard type result, error boxed
+// type Result<T> = std::result::Result<T, Box<dyn error::Error>>;
+//
+// #[derive(Debug)]
+// struct EmptyVec;  // error struct + display for this
+// impl fmt::Display for EmptyVec {
+//     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//         write!(f, "invalid first item to double")
+//     }
+// }
+// impl error::Error for EmptyVec {}
+//
+// fn double_first(vec: Vec<&str>) -> Result<i32> {
+//     let first = vec.first().ok_or(EmptyVec)?;
+//     let parsed = first.parse::<i32>()?;
+//     Ok(2 * parsed)
+// }
+//
+
+// Second implementation, with custom errors
+// type Result<T> = std::result::Result<T, DoubleError>;
+//
+// impl fmt::Display for DoubleError {
+//     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//         match *self {
+//             DoubleError::EmptyVec => write!(f, "please use a vector with at least one element"),
+//             DoubleError::Parse(ref e) => e.fmt(f),
+//         }
+//     }
+// }
+//
+// impl error::Error for DoubleError {
+//     fn source(&self) -> Option<&(dyn Error + 'static)> {
+//         match *self {
+//             DoubleError::EmptyVec => None,
+//             DoubleError::Parse(ref e) => Some(e),
+//         }
+//     }
+// }
+//
+// #[derive(Debug)]
+// enum DoubleError {
+//     EmptyVec,
+//     Parse(ParseIntError)
+// }
+//
+// // conversion from ParseIntError to DoubleError
+// // This will be automatically called by `?` if a `ParseIntError`
+// // needs to be converted into `DoubleError`
+// impl From<ParseIntError> for DoubleError {
+//     fn from(err: ParseIntError) -> Self {
+//         DoubleError::Parse(err)
+//     }
+// }
+
+use expr::Number;
+use eyre::Result;
+use thiserror::Error;
+
+use report::{Traceback, WrapErr};
+
+#[derive(Error, Debug)]
+enum DoubleError {
+    #[error("no first item")]
+    EmptyVec,
+    // the ExprError is the source, the report prints it as "Caused by"
+    #[error("invalid first item")]
+    Parse(#[from] expr::ExprError),
+}
+
+fn print(result: Result<i32, Traceback>) {
+    match result {
+        Ok(n) => {
+            println!("The first double is {}", n)
+        }
+        Err(error) => {
+            println!("Error: {:#}", error);
+            // the traceback goes to stderr, same as Python's
+            eprintln!("{:?}", error);
+        }
+    }
+}
+
+// every wrap_err adds a frame to the traceback, like a call in Python's stack
+fn double_first(vec: Vec<&str>) -> Result<i32, Traceback> {
+    let first = vec.first().ok_or(DoubleError::EmptyVec)?;
+    let parsed: i32 = expr::eval(first)
+        .map_err(DoubleError::Parse)
+        .wrap_err_with(|| format!("parsing the first item {:?}", first))?;
+    parsed
+        .try_mul(2)
+        .map_err(DoubleError::Parse)
+        .wrap_err("doubling it")
+}
+
+fn multiply(first_num_str: &str, second_num_str: &str) -> Result<i32, expr::ExprError> {
+    let first_num: i32 = expr::eval(first_num_str)?;
+    let second_num: i32 = expr::eval(second_num_str)?;
+    first_num.try_mul(second_num)
+}
+
+fn next_birthday(current_age: Option<u8>) -> Option<String> {
+    // if current age is None - returns None;
+    // if current age is Some - inner u8 gets assigned to a value
+    let next_age: u8 = current_age?;
+    Some(format!("Next year I will be {}", next_age))
+}
+
+fn give_commoner(gift: Option<&str>) {
+    match gift {
+        Some("snake") => println!("Yuck, I'm putting this snake back"),
+        Some(inner) => println!("{}? How nice", inner),
+        None => println!("No gift? Oh well"),
+    }
+}
+
+fn give_royal(gift: Option<&str>) {
+    let inside = gift.unwrap(); // throws an error if gift is None
+    if inside == "snake" {
+        panic!("AaaaAaaAA!1");
+    }
+    println!("I love {}s", inside);
+}
+
+// End error handling
+fn v0b_traits() {
+    // informs about functionality a type can share
+    // similar to interfaces or mixins in Python
+
+    println!("---- Traits Start ----");
+    // derive macro automatically implement traits to your structs
+
+    let d1 = D::builder().x(3).build().expect("3 is small enough");
+    let d2 = D::builder()
+        .x(3)
+        .y(5)
+        .build()
+        .expect("3 and 5 are small enough");
+    println!("D sums: {:?}", d1 + d2);
+    // the builder rejects Ds that could overflow when two are added, `+` checks the rest
+    if let Err(e) = D::builder().x(i32::MAX).y(i32::MIN).build() {
+        println!("{}", e);
+    }
+    let half = D::builder()
+        .x(i32::MAX / 2)
+        .build()
+        .expect("within the limit");
+    println!(
+        "D overflows: {:?}",
+        (half + half).and_then(|sum| sum + half)
+    );
+
+    // useful traits:
+    // From or TyFrom to convert from strings to some val
+    // Display - to display and format values
+
+    let plus_one = make_adder_function(1);
+    assert_eq!(plus_one(2), 3);
+
+    let p = make_person(8);
+    println!("Person: {}", p.name());
+
+    let cs = CollegeStudent("Bert".to_string());
+    println!("{}", comp_sci_student_greeting(&cs));
+    println!("{}", comp_sci(&cs));
+    let prog = RustProgrammer("Bob".to_string());
+    println!("{}", comp_sci_vs_programmer(&cs, &prog));
+
+    // Drop runs when a value goes out of scope, in reverse order of creation
+    use guard::{ContextManager, DropRecorder, Exit};
+
+    let recorder = DropRecorder::new();
+    {
+        let _first = recorder.track("first");
+        defer! { recorder.record("deferred"); }
+        let mut counter = Counter::new().record_drop(&recorder);
+        counter.next();
+        let _second = recorder.track("second");
+    }
+    println!("drop order: {:?}", recorder.events());
+
+    // ContextManager is Python's `with`, exit runs however the body ends
+    struct Connection {
+        name: &'static str,
+        log: DropRecorder,
+    }
+
+    impl ContextManager for Connection {
+        type Value = &'static str;
+
+        fn enter(&mut self) -> Self::Value {
+            self.log.record(format!("open {}", self.name));
+            self.name
+        }
+
+        fn exit(&mut self, exit: Exit<'_>) {
+            self.log.record(format!("close {}: {}", self.name, exit));
+        }
+    }
+
+    let log = DropRecorder::new();
+    for (name, query) in [("db", "42"), ("cache", "forty-two")] {
+        let connection = Connection {
+            name,
+            log: log.clone(),
+        };
+        let answer = guard::with(connection, |_| query.parse::<i32>());
+        log.record(match answer {
+            Ok(answer) => format!("answer: {}", answer),
+            Err(_) => String::from("no answer"),
+        });
+    }
+    println!("with: {:?}", log.events());
+
+    println!("---- Traits End ----");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+struct D {
+    x: i32,
+    y: i32,
+}
+
+// x is required, y defaults to 0
+struct DBuilder<X> {
+    x: X,
+    y: i32,
+}
+
+impl D {
+    fn builder() -> DBuilder<Missing> {
+        DBuilder { x: Missing, y: 0 }
+    }
+}
+
+impl DBuilder<Missing> {
+    fn x(self, x: i32) -> DBuilder<Set<i32>> {
+        DBuilder {
+            x: Set(x),
+            y: self.y,
+        }
+    }
+}
+
+impl<X> DBuilder<X> {
+    fn y(self, y: i32) -> Self {
+        DBuilder { y, ..self }
+    }
+}
+
+impl DBuilder<Set<i32>> {
+    fn build(self) -> Result<D, ValidationError> {
+        const LIMIT: i32 = i32::MAX / 2;
+        let within = |n: i32| (-LIMIT..=LIMIT).contains(&n);
+        Validator::new(
+            "D",
+            D {
+                x: self.x.0,
+                y: self.y,
+            },
+        )
+        .rule(
+            |d| within(d.x),
+            |d| format!("x must be within ±{}, got {}", LIMIT, d.x),
+        )
+        .rule(
+            |d| within(d.y),
+            |d| format!("y must be within ±{}, got {}", LIMIT, d.y),
+        )
+        .finish()
+    }
+}
+
+// None on overflow, like `i32::checked_add`
+impl std::ops::Add for D {
+    type Output = Option<D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Some(D {
+            x: self.x.checked_add(rhs.x)?,
+            y: self.y.checked_add(rhs.y)?,
+        })
+    }
+}
+
+impl<T: counter::Int, E: counter::End> Drop for Counter<T, E> {
+    fn drop(&mut self) {
+        // similar to defer in go, this will execute when iterator stops
+        // so you can clean up resources or close connection to db, etc
+        // guard.rs has `defer!` and Python's `with` built on the same idea
+
+        if let Some(recorder) = self.drop_recorder() {
+            recorder.record(format!("Counter dropped at {}", self.current()));
+        }
+    }
+}
+
+// can also define trait for a return type
+
+fn make_adder_function(y: i32) -> impl Fn(i32) -> i32 {
+    move |x: i32| x + y
+}
+
+// super traits - interface overloading
+
+trait Person {
+    fn name(&self) -> String {
+        String::from("Unnamed")
+    }
+}
+
+trait Student: Person {
+    fn university(&self) -> String;
+}
+
+trait Programmer {
+    fn fav_language(&self) -> String;
+}
+
+trait CompSciStudent: Programmer + Student {
+    fn git_username(&self) -> String;
+}
+
+#[allow(dead_code)] // name is never read, Person::name falls back to "Unnamed"
+struct StreetPerson(String);
+#[allow(dead_code)]
+struct RustProgrammer(String);
+struct CollegeStudent(String);
+impl Person for StreetPerson {}
+impl Person for RustProgrammer {}
+
+impl Programmer for RustProgrammer {
+    fn fav_language(&self) -> String {
+        String::from("Rust")
+    }
+}
+
+impl Person for CollegeStudent {
+    fn name(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Programmer for CollegeStudent {
+    fn fav_language(&self) -> String {
+        String::from("From Python to Rust")
+    }
+}
+
+impl Student for CollegeStudent {
+    fn university(&self) -> String {
+        String::from("Community college")
+    }
+}
+
+impl CompSciStudent for CollegeStudent {
+    fn git_username(&self) -> String {
+        self.0.to_lowercase()
+    }
+}
+
+fn comp_sci_student_greeting(student: &dyn CompSciStudent) -> String {
+    format!(
+        "Name is {} I attend {} Fav lang is {} git username is {}",
+        student.name(),
+        student.university(),
+        student.fav_language(),
+        student.git_username()
+    )
+}
+
+fn comp_sci<T: CompSciStudent>(student: &T) -> String {
+    // Same function as above but instead of &dyn stuff it uses generic type
+    comp_sci_student_greeting(student)
+}
+
+fn comp_sci_vs_programmer<T, U>(student: &T, programmer: &U) -> String
+where
+    T: CompSciStudent,
+    U: Programmer + Person,
+{
+    println!(
+        "Programmer named {} loves {}",
+        programmer.name(),
+        programmer.fav_language()
+    );
+    comp_sci(student)
+}
+
+fn make_person(rnd: u8) -> Box<dyn Person> {
+    // return generic value
+    // dyn used for heap allocations which aren't known at the compile time
+    // Box is known at the compile type as it's a pointer to the heap
+    match rnd {
+        0..=3 => Box::new(StreetPerson("Bob".to_string())),
+        4..=6 => Box::new(CollegeStudent("Jake".to_string())),
+        7..=9 => Box::new(RustProgrammer("Don".to_string())),
+        _ => Box::new(StreetPerson("Someone".to_string())),
+    }
+}
+
+// End traits
+
+// Start 0C. Operator Overloading
+fn v0c_operator_overloading() {
+    println!("---- Operator Overloading Start ----");
+
+    let ms = MyString("Foo".to_string());
+    println!("Foo + Bar = {}", ms.clone() + "Bar".to_string());
+    println!("Foo2 + -12 = {}", ms.clone() + -12_i32);
+    println!("100 + Foo3 = {}", 100_i32 + ms.clone());
+    println!("Foo4 * -12 = {}", ms.clone() * -12_i32);
+    println!("Foo4 * 12 = {}", ms.clone() * 12_i32);
+
+    // same as numpy's ndarray, operators work on the whole matrix
+    let a = Matrix::from_rows(vec![vec![1, 2], vec![3, 4]]).expect("rows of the same length");
+    println!("a + I =\n{}", a.clone() + Matrix::identity(2));
+    println!("a * a =\n{}", a.clone() * a.clone());
+    println!("a * 3 =\n{}", a.clone() * 3);
+    println!("a transposed =\n{}", a.transpose());
+    println!("det(a) = {:?}", a.determinant());
+
+    let f =
+        Matrix::from_rows(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).expect("rows of the same length");
+    println!("f inverted =\n{:.2}", f.inverse().expect("det(f) is 10"));
+
+    println!("---- Operator Overloading End ----");
+}
+
+use std::fmt::Write;
+use std::ops;
+
+#[derive(Debug, Clone)]
+struct MyString(String);
+
+impl fmt::Display for MyString {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl ops::Add<String> for MyString {
+    type Output = MyString;
+
+    fn add(self, rhs: String) -> Self::Output {
+        println!("> MyString.add<String>({}) was called", &rhs);
+        MyString(format!("{}{}", self.0, rhs))
+    }
+}
+
+impl ops::Add<i32> for MyString {
+    type Output = MyString;
+
+    fn add(self, rhs: i32) -> Self::Output {
+        println!("> MyString.add<i32>({}) was called", &rhs);
+        MyString(format!("{}{}", self.0, rhs))
+    }
+}
+
+impl ops::Add<MyString> for i32 {
+    type Output = MyString;
+
+    fn add(self, rhs: MyString) -> Self::Output {
+        println!("> i32.add<MyString>({}) was called", &rhs);
+        MyString(format!("{}{}", self, &rhs))
+    }
+}
+
+impl ops::Mul<i32> for MyString {
+    type Output = MyString;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        println!("> MyString.mul<i32>({}) was called", &rhs);
+        let caplen: usize = if rhs < 0 { 0 } else { rhs as usize };
+        let mut temp = String::with_capacity(self.0.len() * caplen);
+        for _ in 0..rhs {
+            temp.write_str(&self.0).expect("writing to string failed");
+        }
+        MyString(temp)
+    }
+}
+
+// End 0C. Operator Overloading
+
+// Start 0D. Files
+fn v0d_files() {
+    println!("---- Files Start ----");
+
+    println!("{:?}", read_sales_csv());
+    println!("{:?}", read_fonts());
+
+    // async-std works like Python's asyncio, block_on is `asyncio.run`
+    println!("{:?}", async_std::task::block_on(read_sales_files()));
+
+    println!("---- Files End ----");
+}
+
+use buckets::Buckets;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn read_sales_csv() -> Result<(), sales::SalesError> {
+    let sales = sales::read_sales("./data/sales.csv")?;
+
+    let by_country = sales::report(&sales, sales::GroupBy::Country);
+    let found = by_country.get("Tanzania").map_or(0, |stats| stats.count);
+    println!("found {}_sales for Tanzania", found);
+
+    for by in [
+        sales::GroupBy::Country,
+        sales::GroupBy::Month,
+        sales::GroupBy::Year,
+    ] {
+        println!("Amount by {:?}", by);
+        for (key, stats) in sales::report(&sales, by) {
+            println!(
+                "> {}: count {} sum {} mean {:.2} min {} max {}",
+                key,
+                stats.count,
+                stats.sum,
+                stats.mean(),
+                stats.min,
+                stats.max
+            );
+        }
+    }
+
+    // binning Amount, same as pandas' `pd.cut(df["Amount"], [0, 50, 150, inf], right=False)`
+    let sizes = Buckets::builder()
+        .bucket("small", 0.0..50.0)
+        .bucket("medium", 50.0..150.0)
+        .bucket("large", 150.0..)
+        .build()
+        .expect("no gaps or overlaps");
+    println!("Amount histogram");
+    println!("{}", sizes.histogram(sales.iter().map(|sale| sale.amount)));
+
+    Ok(())
+}
+
+fn read_fonts() -> io::Result<()> {
+    let data = bytes_from_file("./data/sales.csv")?;
+    println!("Data size {}bytes", data.len());
+
+    let user_home: PathBuf = dirs::home_dir().expect("hno home dir");
+    let user_fonts = Path::new(&user_home).join("Library").join("Fonts");
+    println!("user_home: {:?} user_fonts: {:?}", user_home, user_fonts);
+
+    let _data = bytes_from_file(user_fonts)?;
+
+    Ok(())
+}
+
+fn bytes_from_file(filename: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    std::fs::read(filename)
+}
+
+async fn read_sales_files() -> Result<()> {
+    let data = bytes_from_file_async("./data/sales_2023.csv").await?;
+    println!("Async data size {}bytes", data.len());
+
+    let paths = [
+        PathBuf::from("./data/sales.csv"),
+        PathBuf::from("./data/sales_2023.csv"),
+    ];
+    let sales = sales::read_sales_concurrently(&paths).await?;
+    println!("merged {} sales from {} files", sales.len(), paths.len());
+    for (country, stats) in sales::report(&sales, sales::GroupBy::Country) {
+        println!("> {}: count {} sum {}", country, stats.count, stats.sum);
+    }
+
+    Ok(())
+}
+
+async fn bytes_from_file_async(filename: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    async_std::fs::read(filename.as_ref()).await
+}
+
+// End 0D. Files
@@ -0,0 +1,591 @@
+// Generic N×M matrix, grown out of `struct Matrix(f32, f32)`
+// Python equivalent would be numpy's ndarray, minus broadcasting
+//
+// Operators panic on mismatched dimensions like numpy raises, and on integer overflow, use
+// the `checked_*` methods to get a `MatrixError` instead.
+
+use std::fmt;
+use std::ops;
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MatrixError {
+    #[error("row {row} has {found} columns, expected {expected}")]
+    Ragged {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    #[error("dimension mismatch: {left:?} and {right:?}")]
+    DimensionMismatch {
+        left: (usize, usize),
+        right: (usize, usize),
+    },
+    #[error("matrix is {0}x{1}, expected a square matrix")]
+    NotSquare(usize, usize),
+    #[error("matrix is singular")]
+    Singular,
+    #[error("arithmetic overflow")]
+    Overflow,
+}
+
+/// Numbers a matrix can hold
+pub trait Element:
+    Copy
+    + PartialEq
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Div<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+/// Elements that can be inverted without truncating
+pub trait Float: Element + ops::Neg<Output = Self> + PartialOrd {
+    const EPSILON: Self;
+    fn abs(self) -> Self;
+    fn from_usize(n: usize) -> Self;
+}
+
+macro_rules! impl_element {
+    (int: $($t:ty),*) => {
+        $(impl Element for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        })*
+    };
+    // floats go to infinity instead of overflowing
+    (float: $($t:ty),*) => {
+        $(impl Element for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                Some(self + rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                Some(self - rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                Some(self * rhs)
+            }
+        })*
+    };
+}
+
+impl_element!(int: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_element!(float: f32, f64);
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {
+        $(impl Float for $t {
+            const EPSILON: Self = $t::EPSILON;
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+        })*
+    };
+}
+
+impl_float!(f32, f64);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix<T> {
+    rows: usize,
+    cols: usize,
+    // row-major
+    data: Vec<T>,
+}
+
+impl<T> Matrix<T> {
+    /// Builds a matrix from nested rows, i.e. `[[1, 2], [3, 4]]`
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, MatrixError> {
+        let cols = rows.first().map_or(0, Vec::len);
+        let n_rows = rows.len();
+        let mut data = Vec::with_capacity(n_rows * cols);
+        for (row, values) in rows.into_iter().enumerate() {
+            if values.len() != cols {
+                return Err(MatrixError::Ragged {
+                    row,
+                    expected: cols,
+                    found: values.len(),
+                });
+            }
+            data.extend(values);
+        }
+        Ok(Self {
+            rows: n_rows,
+            cols,
+            data,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            self.data.get(row * self.cols + col)
+        } else {
+            None
+        }
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks panics on 0, an Nx0 matrix has no values to iterate over anyway
+        self.data.chunks(self.cols.max(1))
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.data.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+
+    fn check_shape(&self, other: &Self) -> Result<(), MatrixError> {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: other.shape(),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    pub fn filled(rows: usize, cols: usize, value: T) -> Self {
+        Self {
+            rows,
+            cols,
+            data: vec![value; rows * cols],
+        }
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                data.push(self[(row, col)].clone());
+            }
+        }
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            data,
+        }
+    }
+}
+
+impl<T: Element> Matrix<T> {
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Self::filled(rows, cols, T::ZERO)
+    }
+
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m[(i, i)] = T::ONE;
+        }
+        m
+    }
+
+    /// MatrixError for mismatched shapes and for integer overflow
+    pub fn checked_add(self, rhs: Self) -> Result<Self, MatrixError> {
+        self.check_shape(&rhs)?;
+        self.zip_with(rhs, T::checked_add)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, MatrixError> {
+        self.check_shape(&rhs)?;
+        self.zip_with(rhs, T::checked_sub)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, MatrixError> {
+        if self.cols != rhs.rows {
+            return Err(MatrixError::DimensionMismatch {
+                left: self.shape(),
+                right: rhs.shape(),
+            });
+        }
+        let mut m = Self::zeros(self.rows, rhs.cols);
+        for row in 0..self.rows {
+            for col in 0..rhs.cols {
+                let mut sum = T::ZERO;
+                for k in 0..self.cols {
+                    sum = self[(row, k)]
+                        .checked_mul(rhs[(k, col)])
+                        .and_then(|product| sum.checked_add(product))
+                        .ok_or(MatrixError::Overflow)?;
+                }
+                m[(row, col)] = sum;
+            }
+        }
+        Ok(m)
+    }
+
+    fn zip_with(self, rhs: Self, f: impl Fn(T, T) -> Option<T>) -> Result<Self, MatrixError> {
+        Ok(Self {
+            rows: self.rows,
+            cols: self.cols,
+            data: self
+                .data
+                .into_iter()
+                .zip(rhs.data)
+                .map(|(a, b)| f(a, b).ok_or(MatrixError::Overflow))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn square_size(&self) -> Result<usize, MatrixError> {
+        if !self.is_square() {
+            return Err(MatrixError::NotSquare(self.rows, self.cols));
+        }
+        Ok(self.rows)
+    }
+}
+
+impl<T: Element + ops::Neg<Output = T>> Matrix<T> {
+    /// Fraction-free Bareiss elimination, so it stays exact for integers.
+    /// The products along the way are bigger than the result, they can overflow even when
+    /// the determinant itself would fit in `T`
+    pub fn determinant(&self) -> Result<T, MatrixError> {
+        let n = self.square_size()?;
+        if n == 0 {
+            return Ok(T::ONE);
+        }
+
+        let mut m = self.clone();
+        let mut sign = T::ONE;
+        let mut prev = T::ONE;
+        for k in 0..n - 1 {
+            if m[(k, k)] == T::ZERO {
+                let Some(pivot) = (k + 1..n).find(|&i| m[(i, k)] != T::ZERO) else {
+                    return Ok(T::ZERO);
+                };
+                m.swap_rows(k, pivot);
+                sign = -sign;
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    let a = m[(i, j)].checked_mul(m[(k, k)]);
+                    let b = m[(i, k)].checked_mul(m[(k, j)]);
+                    let diff = a.zip(b).and_then(|(a, b)| a.checked_sub(b));
+                    m[(i, j)] = diff.ok_or(MatrixError::Overflow)? / prev;
+                }
+            }
+            prev = m[(k, k)];
+        }
+        Ok(sign * m[(n - 1, n - 1)])
+    }
+}
+
+impl<T: Float> Matrix<T> {
+    /// Gauss-Jordan elimination with partial pivoting
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let n = self.square_size()?;
+        let mut m = self.clone();
+        let mut inv = Self::identity(n);
+        // relative to the largest entry, so scaling the matrix doesn't make it singular
+        let largest = self
+            .data
+            .iter()
+            .map(|x| x.abs())
+            .fold(T::ZERO, |a, b| if b > a { b } else { a });
+        let tolerance = largest * T::EPSILON * T::from_usize(n);
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| {
+                    let (a, b) = (m[(a, k)].abs(), m[(b, k)].abs());
+                    a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .unwrap_or(k);
+            if m[(pivot, k)].abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            m.swap_rows(k, pivot);
+            inv.swap_rows(k, pivot);
+
+            let p = m[(k, k)];
+            for col in 0..n {
+                m[(k, col)] = m[(k, col)] / p;
+                inv[(k, col)] = inv[(k, col)] / p;
+            }
+            for row in (0..n).filter(|&row| row != k) {
+                let factor = m[(row, k)];
+                for col in 0..n {
+                    m[(row, col)] = m[(row, col)] - factor * m[(k, col)];
+                    inv[(row, col)] = inv[(row, col)] - factor * inv[(k, col)];
+                }
+            }
+        }
+        Ok(inv)
+    }
+}
+
+impl<T> ops::Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl<T> ops::IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(
+            row < self.rows && col < self.cols,
+            "index ({}, {}) out of bounds for a {}x{} matrix",
+            row,
+            col,
+            self.rows,
+            self.cols
+        );
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+impl<T: Element> ops::Add for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Element> ops::Sub for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Element> ops::Mul for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Applied to every element, overflow panics or wraps like the std operators do
+impl<T: Element> ops::Add<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x = *x + rhs);
+        self
+    }
+}
+
+/// Applied to every element, overflow panics or wraps like the std operators do
+impl<T: Element> ops::Sub<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x = *x - rhs);
+        self
+    }
+}
+
+/// Applied to every element, overflow panics or wraps like the std operators do
+impl<T: Element> ops::Mul<T> for Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x = *x * rhs);
+        self
+    }
+}
+
+// one row per line, the formatter is passed on so `{:.2}` applies to every element
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.iter_rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, value) in row.iter().enumerate() {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+                value.fmt(f)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m<T>(rows: Vec<Vec<T>>) -> Matrix<T> {
+        Matrix::from_rows(rows).unwrap()
+    }
+
+    #[test]
+    fn builds_from_rows() {
+        let a = m(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(a.shape(), (2, 3));
+        assert_eq!(a[(1, 2)], 6);
+        assert_eq!(a.get(2, 0), None);
+        assert_eq!(
+            Matrix::from_rows(vec![vec![1, 2], vec![3]]),
+            Err(MatrixError::Ragged {
+                row: 1,
+                expected: 2,
+                found: 1
+            })
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = m(vec![vec![1, 2], vec![3, 4]]);
+        let b = m(vec![vec![5, 6], vec![7, 8]]);
+        assert_eq!(a.clone() + b.clone(), m(vec![vec![6, 8], vec![10, 12]]));
+        assert_eq!(b.clone() - a.clone(), Matrix::filled(2, 2, 4));
+        assert_eq!(a.clone() * b, m(vec![vec![19, 22], vec![43, 50]]));
+        assert_eq!(a.clone() * 2, m(vec![vec![2, 4], vec![6, 8]]));
+        assert_eq!(a.clone() * Matrix::identity(2), a);
+        assert_eq!(
+            a.checked_add(Matrix::zeros(2, 3)),
+            Err(MatrixError::DimensionMismatch {
+                left: (2, 2),
+                right: (2, 3)
+            })
+        );
+    }
+
+    #[test]
+    fn overflow_is_an_error() {
+        let max = m(vec![vec![i32::MAX]]);
+        assert_eq!(
+            max.clone().checked_add(max.clone()),
+            Err(MatrixError::Overflow)
+        );
+        let min = m(vec![vec![i32::MIN]]);
+        assert_eq!(min.checked_sub(max.clone()), Err(MatrixError::Overflow));
+        let row = m(vec![vec![i32::MAX, 1]]);
+        let col = m(vec![vec![2], vec![1]]);
+        assert_eq!(row.clone().checked_mul(col), Err(MatrixError::Overflow));
+        // the product fits, the sum of the products doesn't
+        let ones = m(vec![vec![1], vec![1]]);
+        assert_eq!(row.checked_mul(ones), Err(MatrixError::Overflow));
+        assert_eq!(max.clone().checked_sub(max), Ok(m(vec![vec![0]])));
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn mismatched_product_panics() {
+        let _ = Matrix::<i32>::zeros(2, 3) * Matrix::zeros(2, 3);
+    }
+
+    #[test]
+    fn transposes() {
+        let a = m(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(a.transpose(), m(vec![vec![1, 4], vec![2, 5], vec![3, 6]]));
+        assert_eq!(a.transpose().transpose(), a);
+    }
+
+    #[test]
+    fn determinant() {
+        assert_eq!(m(vec![vec![1, 2], vec![3, 4]]).determinant(), Ok(-2));
+        let a = m(vec![vec![0, 2, 1], vec![1, 0, 3], vec![4, 5, 6]]);
+        assert_eq!(a.determinant(), Ok(17));
+        assert_eq!(Matrix::<i64>::identity(4).determinant(), Ok(1));
+        assert_eq!(
+            m(vec![vec![1, 2], vec![2, 4]]).determinant(),
+            Ok(0),
+            "singular"
+        );
+        assert_eq!(
+            Matrix::<i32>::zeros(2, 3).determinant(),
+            Err(MatrixError::NotSquare(2, 3))
+        );
+        // 2^20 * 2^20 overflows in the first step, even though the result is 0
+        let big = m(vec![vec![1 << 20, 1 << 20], vec![1 << 20, 1 << 20]]);
+        assert_eq!(big.determinant(), Err(MatrixError::Overflow));
+        let big = m(vec![vec![1i64 << 20, 1 << 20], vec![1 << 20, 1 << 20]]);
+        assert_eq!(big.determinant(), Ok(0));
+    }
+
+    #[test]
+    fn inverse() {
+        let a = m(vec![vec![4.0, 7.0], vec![2.0, 6.0]]);
+        let product = a.clone() * a.inverse().unwrap();
+        for row in 0..2 {
+            for col in 0..2 {
+                let expected = if row == col { 1.0 } else { 0.0 };
+                assert!((product[(row, col)] - expected).abs() < 1e-12);
+            }
+        }
+        assert_eq!(
+            m(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).inverse(),
+            Err(MatrixError::Singular)
+        );
+        assert_eq!(
+            Matrix::<f64>::zeros(2, 2).inverse(),
+            Err(MatrixError::Singular)
+        );
+
+        // tiny but well conditioned
+        let inverse = (Matrix::<f64>::identity(3) * 1e-17).inverse().unwrap();
+        for row in 0..3 {
+            for col in 0..3 {
+                let expected = if row == col { 1e17 } else { 0.0 };
+                assert!((inverse[(row, col)] - expected).abs() < 1e5);
+            }
+        }
+    }
+
+    #[test]
+    fn displays_rows() {
+        let a = m(vec![vec![1.0, 0.5], vec![-2.0, 3.25]]);
+        assert_eq!(format!("{}", a), "[1, 0.5]\n[-2, 3.25]");
+        assert_eq!(format!("{:.1}", a), "[1.0, 0.5]\n[-2.0, 3.2]");
+    }
+}
@@ -0,0 +1,216 @@
+// Pangram and alphabet coverage, which letters of an alphabet a text uses and how often
+// Letters are matched case insensitively, and accented letters count as their base letter
+// unless the alphabet has them: "á" is "a" in Latin, "ñ" stays "ñ" in Spanish.
+
+use std::fmt;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    pub name: &'static str,
+    /// lowercase, in alphabetical order
+    pub letters: &'static str,
+}
+
+pub const LATIN: Alphabet = Alphabet {
+    name: "Latin",
+    letters: "abcdefghijklmnopqrstuvwxyz",
+};
+
+pub const GERMAN: Alphabet = Alphabet {
+    name: "German",
+    letters: "aäbcdefghijklmnoöpqrsßtuüvwxyz",
+};
+
+pub const SPANISH: Alphabet = Alphabet {
+    name: "Spanish",
+    letters: "abcdefghijklmnñopqrstuvwxyz",
+};
+
+pub const RUSSIAN: Alphabet = Alphabet {
+    name: "Russian",
+    letters: "абвгдеёжзийклмнопрстуфхцчшщъыьэюя",
+};
+
+pub const UKRAINIAN: Alphabet = Alphabet {
+    name: "Ukrainian",
+    letters: "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя",
+};
+
+pub const GREEK: Alphabet = Alphabet {
+    name: "Greek",
+    letters: "αβγδεζηθικλμνξοπρστυφχψω",
+};
+
+pub const ALPHABETS: &[Alphabet] = &[LATIN, GERMAN, SPANISH, RUSSIAN, UKRAINIAN, GREEK];
+
+impl Alphabet {
+    pub fn contains(&self, letter: char) -> bool {
+        self.letters.contains(letter)
+    }
+
+    // the letter of this alphabet `c` counts as, if any
+    fn letter(&self, c: char) -> Option<char> {
+        let c = match c {
+            'ς' => 'σ',
+            c => c,
+        };
+        if self.contains(c) {
+            return Some(c);
+        }
+        // é -> e + U+0301 -> e
+        let base = c.nfd().find(|&c| !is_combining_mark(c))?;
+        self.contains(base).then_some(base)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub alphabet: Alphabet,
+    /// every letter of the alphabet in order, with how many times it is used
+    pub frequencies: Vec<(char, usize)>,
+    /// letters of other alphabets, i.e. Cyrillic in a Latin text
+    pub foreign: usize,
+}
+
+impl Analysis {
+    pub fn missing(&self) -> Vec<char> {
+        self.frequencies
+            .iter()
+            .filter(|&&(_, count)| count == 0)
+            .map(|&(letter, _)| letter)
+            .collect()
+    }
+
+    pub fn is_pangram(&self) -> bool {
+        self.frequencies.iter().all(|&(_, count)| count > 0)
+    }
+
+    /// Letters sorted by how often they are used, ties in alphabetical order
+    pub fn most_common(&self) -> Vec<(char, usize)> {
+        let mut common: Vec<(char, usize)> = self
+            .frequencies
+            .iter()
+            .copied()
+            .filter(|&(_, count)| count > 0)
+            .collect();
+        // stable, so ties keep the alphabet order
+        common.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        common
+    }
+
+    pub fn count(&self, letter: char) -> usize {
+        self.frequencies
+            .iter()
+            .find(|&&(l, _)| l == letter)
+            .map_or(0, |&(_, count)| count)
+    }
+}
+
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_pangram() {
+            return write!(f, "{} pangram", self.alphabet.name);
+        }
+        let missing: Vec<String> = self.missing().iter().map(char::to_string).collect();
+        write!(
+            f,
+            "not a {} pangram, missing: {}",
+            self.alphabet.name,
+            missing.join(", ")
+        )
+    }
+}
+
+pub fn analyze(text: &str, alphabet: Alphabet) -> Analysis {
+    let mut frequencies: Vec<(char, usize)> = alphabet.letters.chars().map(|c| (c, 0)).collect();
+    let mut foreign = 0;
+    for c in text.nfc().flat_map(char::to_lowercase) {
+        match alphabet.letter(c) {
+            Some(letter) => {
+                if let Some(entry) = frequencies.iter_mut().find(|(l, _)| *l == letter) {
+                    entry.1 += 1;
+                }
+            }
+            None if c.is_alphabetic() => foreign += 1,
+            None => {}
+        }
+    }
+    Analysis {
+        alphabet,
+        frequencies,
+        foreign,
+    }
+}
+
+/// The alphabet most of the text's letters belong to, the smallest one on a tie
+pub fn detect(text: &str) -> Option<Alphabet> {
+    ALPHABETS
+        .iter()
+        .map(|&alphabet| {
+            let analysis = analyze(text, alphabet);
+            let used: usize = analysis.frequencies.iter().map(|&(_, count)| count).sum();
+            (alphabet, used, analysis.missing().len())
+        })
+        .filter(|&(_, used, _)| used > 0)
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.2.cmp(&a.2)))
+        .map(|(alphabet, _, _)| alphabet)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_missing_letters() {
+        let analysis = analyze("the quick brown fox jumps over the lazy", LATIN);
+        assert!(!analysis.is_pangram());
+        assert_eq!(analysis.missing(), vec!['d', 'g']);
+        assert_eq!(analysis.to_string(), "not a Latin pangram, missing: d, g");
+        assert_eq!(analysis.count('o'), 3);
+        assert_eq!(analysis.most_common()[..3], [('e', 3), ('o', 3), ('h', 2)]);
+        assert!(analyze("The quick brown fox jumps over the lazy dog!", LATIN).is_pangram());
+    }
+
+    #[test]
+    fn folds_accents_not_in_the_alphabet() {
+        let latin = analyze("Pójdźże, kiń tę chmurność w głąb flaszy!", LATIN);
+        assert_eq!(latin.count('o'), 2);
+        assert_eq!(latin.count('z'), 3);
+        // ł doesn't decompose into l
+        assert_eq!(latin.foreign, 1);
+
+        let spanish = analyze("El pingüino Wenceslao hizo kilómetros bajo exhaustiva lluvia y frío, añoraba a su querido cachorro.", SPANISH);
+        assert!(spanish.is_pangram());
+        assert_eq!(spanish.count('ñ'), 1);
+        assert_eq!(analyze("año", LATIN).count('n'), 1);
+    }
+
+    #[test]
+    fn other_alphabets() {
+        assert!(analyze(
+            "Съешь же ещё этих мягких французских булок, да выпей чаю",
+            RUSSIAN
+        )
+        .is_pangram());
+        assert!(analyze("Ξεσκεπάζω την ψυχοφθόρα βδελυγμία", GREEK).is_pangram());
+        assert!(analyze(
+            "Victor jagt zwölf Boxkämpfer quer über den großen Sylter Deich",
+            GERMAN
+        )
+        .is_pangram());
+        let english_in_russian = analyze("hello мир", RUSSIAN);
+        assert_eq!(english_in_russian.foreign, 5);
+        assert_eq!(english_in_russian.missing().len(), 30);
+    }
+
+    #[test]
+    fn detects_alphabets() {
+        assert_eq!(detect("the lazy dog"), Some(LATIN));
+        assert_eq!(detect("Ξεσκεπάζω"), Some(GREEK));
+        assert_eq!(detect("Їжак ґедзь"), Some(UKRAINIAN));
+        assert_eq!(detect("1234"), None);
+    }
+}
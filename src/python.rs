@@ -0,0 +1,146 @@
+// Python extension module, so the Rust versions can be called side by side with Python code
+// Build with `maturin develop`, then in Python: `import python2rust`
+
+// pyo3 0.20 macros expand to impls the newer compilers warn about
+#![allow(non_local_definitions)]
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+#[pyfunction]
+fn naive_capitalize(s: &str) -> String {
+    crate::naive_capitalize(s)
+}
+
+#[pyfunction]
+fn ascii_capitalize(s: &str) -> String {
+    crate::ascii_capitalize(s)
+}
+
+#[pyfunction]
+fn double_first(items: Vec<&str>) -> PyResult<i32> {
+    crate::double_first(items).map_err(|e| PyValueError::new_err(format!("{:#}", e)))
+}
+
+#[pyfunction]
+fn multiply(first_num_str: &str, second_num_str: &str) -> PyResult<i32> {
+    crate::multiply(first_num_str, second_num_str).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+#[pyclass(name = "Counter")]
+struct PyCounter(crate::Counter<i64>);
+
+#[pymethods]
+impl PyCounter {
+    // same arguments as range(), defaults count 1 to 5 like Counter::new()
+    #[new]
+    #[pyo3(signature = (start=1, stop=6, step=1))]
+    fn new(start: i64, stop: i64, step: i64) -> PyResult<Self> {
+        if step == 0 {
+            return Err(PyValueError::new_err("Counter() arg 3 must not be zero"));
+        }
+        Ok(Self(crate::Counter::range(start, stop, step)))
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<i64> {
+        slf.0.next()
+    }
+}
+
+#[derive(FromPyObject)]
+enum Operand {
+    Int(i32),
+    Str(String),
+}
+
+// operators with any other type return NotImplemented, so Python raises a TypeError
+#[pyclass(name = "MyString")]
+#[derive(Clone)]
+struct PyMyString(crate::MyString);
+
+#[pymethods]
+impl PyMyString {
+    #[new]
+    fn new(s: String) -> Self {
+        Self(crate::MyString(s))
+    }
+
+    fn __add__(&self, other: Operand) -> Self {
+        match other {
+            Operand::Int(n) => Self(self.0.clone() + n),
+            Operand::Str(s) => Self(self.0.clone() + s),
+        }
+    }
+
+    fn __radd__(&self, other: i32) -> Self {
+        Self(other + self.0.clone())
+    }
+
+    fn __mul__(&self, other: i32) -> Self {
+        Self(self.0.clone() * other)
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MyString({:?})", self.0 .0)
+    }
+}
+
+#[pymodule]
+fn python2rust(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(naive_capitalize, m)?)?;
+    m.add_function(wrap_pyfunction!(ascii_capitalize, m)?)?;
+    m.add_function(wrap_pyfunction!(double_first, m)?)?;
+    m.add_function(wrap_pyfunction!(multiply, m)?)?;
+    m.add_class::<PyCounter>()?;
+    m.add_class::<PyMyString>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pyo3::types::PyDict;
+
+    #[test]
+    fn module_behaves_like_rust() {
+        pyo3::prepare_freethreaded_python();
+        Python::with_gil(|py| {
+            let module = PyModule::new(py, "python2rust").unwrap();
+            python2rust(py, module).unwrap();
+            let locals = PyDict::new(py);
+            locals.set_item("p2r", module).unwrap();
+
+            let eval = |code: &str| py.eval(code, None, Some(locals)).unwrap().to_string();
+            assert_eq!(eval("p2r.naive_capitalize('óleg')"), "Óleg");
+            assert_eq!(eval("p2r.ascii_capitalize('óleg')"), "óleg");
+            assert_eq!(eval("p2r.double_first(['42', '93'])"), "84");
+            assert_eq!(eval("p2r.multiply('10', '2')"), "20");
+            assert_eq!(eval("list(p2r.Counter())"), "[1, 2, 3, 4, 5]");
+            assert_eq!(eval("list(p2r.Counter(10, -3, -4))"), "[10, 6, 2, -2]");
+            assert_eq!(eval("str(p2r.MyString('Foo') + 'Bar')"), "FooBar");
+            assert_eq!(eval("str(100 + p2r.MyString('Foo'))"), "100Foo");
+            assert_eq!(eval("str(p2r.MyString('Ab') * 3)"), "AbAbAb");
+
+            for code in [
+                "p2r.double_first(['tofu'])",
+                "p2r.multiply('2147483647', '2')",
+                "p2r.Counter(1, 5, 0)",
+            ] {
+                let error = py.eval(code, None, Some(locals)).unwrap_err();
+                assert!(error.is_instance_of::<PyValueError>(py));
+            }
+            let error = py
+                .eval("p2r.MyString('Foo') * 'x'", None, Some(locals))
+                .unwrap_err();
+            assert!(error.is_instance_of::<pyo3::exceptions::PyTypeError>(py));
+        });
+    }
+}
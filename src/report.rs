@@ -0,0 +1,256 @@
+// Errors that print like Python tracebacks
+//
+// let n: i32 = text.parse().wrap_err("reading the count")?;
+//
+// Every `wrap_err` and every `?` that turns an error into a Traceback remembers its call
+// site. `{:?}` prints them most recent call last, then the error and its `source()` chain:
+//
+// Traceback (most recent call last):
+//   File "src/lib.rs", line 12, column 31
+//     reading the count
+// ParseIntError: invalid digit found in string
+//
+// `{}` is the outermost message and `{:#}` the whole chain on one line, same as eyre.
+// A backtrace is captured too when RUST_BACKTRACE or RUST_LIB_BACKTRACE is set
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt;
+use std::panic::Location;
+use std::path::Path;
+
+/// One call site the error passed through
+#[derive(Debug, Clone)]
+pub struct Frame {
+    pub location: &'static Location<'static>,
+    /// Set by `wrap_err`, None where `?` converted the error
+    pub context: Option<String>,
+}
+
+pub struct Traceback {
+    error: Box<dyn Error + Send + Sync + 'static>,
+    type_name: &'static str,
+    // innermost first
+    frames: Vec<Frame>,
+    backtrace: Backtrace,
+}
+
+impl Traceback {
+    #[track_caller]
+    fn new<E: Error + Send + Sync + 'static>(error: E, context: Option<String>) -> Self {
+        Traceback {
+            error: Box::new(error),
+            type_name: short_type_name::<E>(),
+            frames: vec![Frame {
+                location: Location::caller(),
+                context,
+            }],
+            backtrace: Backtrace::capture(),
+        }
+    }
+
+    /// Innermost first
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// The error and all its sources, outermost first
+    pub fn chain(&self) -> impl Iterator<Item = &(dyn Error + 'static)> {
+        let error: &(dyn Error + 'static) = &*self.error;
+        std::iter::successors(Some(error), |&e| e.source())
+    }
+
+    pub fn root_cause(&self) -> &(dyn Error + 'static) {
+        self.chain().last().expect("chain starts with the error")
+    }
+
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    fn contexts(&self) -> impl Iterator<Item = &str> {
+        self.frames
+            .iter()
+            .rev()
+            .filter_map(|f| f.context.as_deref())
+    }
+}
+
+// like `?` in eyre, the From impl rules out Traceback implementing Error itself
+impl<E: Error + Send + Sync + 'static> From<E> for Traceback {
+    #[track_caller]
+    fn from(error: E) -> Self {
+        Traceback::new(error, None)
+    }
+}
+
+pub trait WrapErr<T> {
+    /// Adds a context frame at the caller's location
+    fn wrap_err(self, context: impl fmt::Display) -> Result<T, Traceback>;
+
+    /// Same as `wrap_err`, the message is only built on errors
+    fn wrap_err_with<D: fmt::Display>(self, context: impl FnOnce() -> D) -> Result<T, Traceback>;
+}
+
+impl<T, E: IntoTraceback> WrapErr<T> for Result<T, E> {
+    #[track_caller]
+    fn wrap_err(self, context: impl fmt::Display) -> Result<T, Traceback> {
+        self.wrap_err_with(|| context)
+    }
+
+    #[track_caller]
+    fn wrap_err_with<D: fmt::Display>(self, context: impl FnOnce() -> D) -> Result<T, Traceback> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(error.into_traceback(context().to_string())),
+        }
+    }
+}
+
+/// Errors that `wrap_err` accepts, any `Error` or a Traceback that gets one more frame
+pub trait IntoTraceback {
+    #[track_caller]
+    fn into_traceback(self, context: String) -> Traceback;
+}
+
+impl<E: Error + Send + Sync + 'static> IntoTraceback for E {
+    #[track_caller]
+    fn into_traceback(self, context: String) -> Traceback {
+        Traceback::new(self, Some(context))
+    }
+}
+
+impl IntoTraceback for Traceback {
+    #[track_caller]
+    fn into_traceback(mut self, context: String) -> Traceback {
+        self.frames.push(Frame {
+            location: Location::caller(),
+            context: Some(context),
+        });
+        self
+    }
+}
+
+/// `{}` is the outermost message, `{:#}` every message joined with ": "
+impl fmt::Display for Traceback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !f.alternate() {
+            return match self.contexts().next() {
+                Some(context) => write!(f, "{}", context),
+                None => write!(f, "{}", self.error),
+            };
+        }
+        let errors = self.chain().map(|e| e.to_string());
+        let messages: Vec<String> = self.contexts().map(String::from).chain(errors).collect();
+        write!(f, "{}", messages.join(": "))
+    }
+}
+
+/// The whole traceback
+impl fmt::Debug for Traceback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Traceback (most recent call last):")?;
+        for frame in self.frames.iter().rev() {
+            let location = frame.location;
+            writeln!(
+                f,
+                "  File \"{}\", line {}, column {}",
+                location.file(),
+                location.line(),
+                location.column()
+            )?;
+            // Python shows the line of code, the context says more when there is one
+            if let Some(text) = frame.context.clone().or_else(|| source_line(location)) {
+                writeln!(f, "    {}", text)?;
+            }
+        }
+        write!(f, "{}: {}", self.type_name, self.error)?;
+        for cause in self.chain().skip(1) {
+            write!(f, "\nCaused by: {}", cause)?;
+        }
+        if self.backtrace.status() == BacktraceStatus::Captured {
+            write!(f, "\n\nStack backtrace:\n{}", self.backtrace)?;
+        }
+        Ok(())
+    }
+}
+
+// `python2rust::DoubleError` -> `DoubleError`
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let path = name.split('<').next().unwrap_or(name);
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+// paths of this crate's code are relative to its manifest, joining keeps absolute ones as is.
+// Only works where the sources are still around, the frame just has no line otherwise
+fn source_line(location: &Location<'_>) -> Option<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(location.file());
+    let source = std::fs::read_to_string(path).ok()?;
+    let line = source.lines().nth(location.line() as usize - 1)?;
+    Some(line.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::ParseIntError;
+
+    fn parse(text: &str) -> Result<i32, Traceback> {
+        Ok(text.parse::<i32>()?)
+    }
+
+    fn parse_all(texts: &[&str]) -> Result<Vec<i32>, Traceback> {
+        texts
+            .iter()
+            .map(|text| parse(text).wrap_err_with(|| format!("parsing {:?}", text)))
+            .collect()
+    }
+
+    #[test]
+    fn records_every_call_site() {
+        let result = parse_all(&["1", "tofu"]);
+        let here = line!() + 1;
+        let error = result.wrap_err("reading numbers").unwrap_err();
+        // `?` in parse, wrap_err_with in parse_all, then wrap_err here
+        let lines: Vec<u32> = error.frames().iter().map(|f| f.location.line()).collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0] < lines[1] && lines[1] < here);
+        assert_eq!(lines[2], here);
+        assert!(error
+            .frames()
+            .iter()
+            .all(|f| f.location.file() == "src/report.rs"));
+        assert_eq!(error.to_string(), "reading numbers");
+        assert_eq!(
+            format!("{:#}", error),
+            r#"reading numbers: parsing "tofu": invalid digit found in string"#
+        );
+        assert!(parse_all(&["1", "2"]).is_ok());
+    }
+
+    #[derive(thiserror::Error, Debug)]
+    #[error("bad config")]
+    struct ConfigError(#[source] ParseIntError);
+
+    #[test]
+    fn prints_like_a_python_traceback() {
+        let result: Result<i32, _> = "x".parse().map_err(ConfigError);
+        let error = result.wrap_err("loading config").unwrap_err();
+        let traceback = format!("{:?}", error);
+        let lines: Vec<&str> = traceback.lines().take(5).collect();
+        assert_eq!(lines[0], "Traceback (most recent call last):");
+        assert!(lines[1].starts_with("  File \"src/report.rs\", line "));
+        assert_eq!(lines[2], "    loading config");
+        assert_eq!(lines[3], "ConfigError: bad config");
+        assert_eq!(lines[4], "Caused by: invalid digit found in string");
+        assert_eq!(
+            error.root_cause().to_string(),
+            "invalid digit found in string"
+        );
+
+        // without context the frame shows the line of code, like Python does
+        let traceback = format!("{:?}", parse("x").unwrap_err());
+        assert!(traceback.contains("    Ok(text.parse::<i32>()?)\n"));
+    }
+}
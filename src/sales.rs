@@ -0,0 +1,332 @@
+// Sales report engine for data/sales.csv
+// Python equivalent is roughly `df.groupby("Country")["Amount"].agg(["count", "sum", "mean", "min", "max"])`
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::BufRead;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use crate::csv::{self, CsvError};
+
+#[derive(Error, Debug)]
+pub enum SalesError {
+    #[error(transparent)]
+    Csv(#[from] CsvError),
+    #[error("line {line}, column '{column}': {message}")]
+    Parse {
+        line: usize,
+        column: &'static str,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+impl Date {
+    pub fn is_leap_year(&self) -> bool {
+        (self.year % 4 == 0 && self.year % 100 != 0) || self.year % 400 == 0
+    }
+
+    /// 0 for a month that doesn't exist
+    pub fn days_in_month(&self) -> u32 {
+        match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if self.is_leap_year() => 29,
+            2 => 28,
+            _ => 0,
+        }
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    // expects ISO dates, i.e. 2022-03-02
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.trim().split('-').collect();
+        let [year, month, day] = parts.as_slice() else {
+            return Err(format!("invalid date '{}', expected YYYY-MM-DD", s));
+        };
+        let invalid = |e: std::num::ParseIntError| format!("invalid date '{}': {}", s, e);
+        let date = Date {
+            year: year.parse().map_err(invalid)?,
+            month: month.parse().map_err(invalid)?,
+            day: day.parse().map_err(invalid)?,
+        };
+        if !(1..=12).contains(&date.month) || !(1..=date.days_in_month()).contains(&date.day) {
+            return Err(format!("invalid date '{}', month or day out of range", s));
+        }
+        Ok(date)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sale {
+    pub country: String,
+    pub date: Date,
+    pub amount: f64,
+}
+
+/// Column used to group sales in a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Country,
+    Month,
+    Year,
+}
+
+impl GroupBy {
+    fn key(&self, sale: &Sale) -> String {
+        match self {
+            GroupBy::Country => sale.country.clone(),
+            GroupBy::Month => format!("{:04}-{:02}", sale.date.year, sale.date.month),
+            GroupBy::Year => format!("{:04}", sale.date.year),
+        }
+    }
+}
+
+/// Aggregated `Amount` values of a single group
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    fn new(amount: f64) -> Self {
+        Self {
+            count: 1,
+            sum: amount,
+            min: amount,
+            max: amount,
+        }
+    }
+
+    fn add(&mut self, amount: f64) {
+        self.count += 1;
+        self.sum += amount;
+        self.min = self.min.min(amount);
+        self.max = self.max.max(amount);
+    }
+
+    pub fn mean(&self) -> f64 {
+        self.sum / self.count as f64
+    }
+}
+
+/// Group key to stats, sorted by key
+pub type Report = BTreeMap<String, Stats>;
+
+pub fn report(sales: &[Sale], by: GroupBy) -> Report {
+    let mut report = Report::new();
+    for sale in sales {
+        report
+            .entry(by.key(sale))
+            .and_modify(|stats| stats.add(sale.amount))
+            .or_insert_with(|| Stats::new(sale.amount));
+    }
+    report
+}
+
+pub fn read_sales(path: impl AsRef<Path>) -> Result<Vec<Sale>, SalesError> {
+    sales_from(csv::Reader::from_path(path)?)
+}
+
+pub fn parse_sales(reader: impl BufRead) -> Result<Vec<Sale>, SalesError> {
+    sales_from(csv::Reader::new(reader)?)
+}
+
+fn sales_from<R: BufRead>(reader: csv::Reader<R>) -> Result<Vec<Sale>, SalesError> {
+    let columns = Columns::new(reader.headers())?;
+    reader.map(|record| columns.sale(&record?)).collect()
+}
+
+pub async fn read_sales_async(path: impl AsRef<Path>) -> Result<Vec<Sale>, SalesError> {
+    let mut reader = csv::AsyncReader::from_path(path).await?;
+    let columns = Columns::new(reader.headers())?;
+
+    let mut sales = Vec::new();
+    while let Some(record) = reader.next_record().await {
+        sales.push(columns.sale(&record?)?);
+    }
+    Ok(sales)
+}
+
+/// Reads every file at the same time and merges the sales, in the order of `paths`
+/// Python equivalent is `asyncio.gather(*(read_sales(path) for path in paths))`
+pub async fn read_sales_concurrently(paths: &[PathBuf]) -> Result<Vec<Sale>, SalesError> {
+    let tasks: Vec<_> = paths
+        .iter()
+        .cloned()
+        .map(|path| async_std::task::spawn(read_sales_async(path)))
+        .collect();
+
+    let mut sales = Vec::new();
+    for task in tasks {
+        sales.extend(task.await?);
+    }
+    Ok(sales)
+}
+
+// columns are looked up by header, so their order in the file doesn't matter
+struct Columns {
+    country: usize,
+    date: usize,
+    amount: usize,
+}
+
+impl Columns {
+    fn new(headers: &csv::Headers) -> Result<Self, SalesError> {
+        Ok(Self {
+            country: headers.require("Country")?,
+            date: headers.require("Date")?,
+            amount: headers.require("Amount")?,
+        })
+    }
+
+    fn sale(&self, record: &csv::Record) -> Result<Sale, SalesError> {
+        let field = |index: usize| record.get(index).unwrap_or_default().trim();
+        let parse_error = |column, message| SalesError::Parse {
+            line: record.line(),
+            column,
+            message,
+        };
+
+        let amount = field(self.amount);
+        Ok(Sale {
+            country: field(self.country).to_string(),
+            date: field(self.date)
+                .parse()
+                .map_err(|message| parse_error("Date", message))?,
+            amount: amount.parse().map_err(|e| {
+                parse_error("Amount", format!("invalid amount '{}': {}", amount, e))
+            })?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SALES: &str = "Country,Date,Amount
+Tanzania,2022-03-02,100
+USA,2022-09-12,23
+Tanzania,2022-05-02,188
+USA,2023-09-30,7
+";
+
+    #[test]
+    fn parses_typed_records() {
+        let sales = parse_sales(SALES.as_bytes()).unwrap();
+        assert_eq!(sales.len(), 4);
+        assert_eq!(
+            sales[0],
+            Sale {
+                country: "Tanzania".to_string(),
+                date: Date {
+                    year: 2022,
+                    month: 3,
+                    day: 2
+                },
+                amount: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn groups_by_country() {
+        let sales = parse_sales(SALES.as_bytes()).unwrap();
+        let report = report(&sales, GroupBy::Country);
+        let tanzania = report["Tanzania"];
+        assert_eq!(tanzania.count, 2);
+        assert_eq!(tanzania.sum, 288.0);
+        assert_eq!(tanzania.mean(), 144.0);
+        assert_eq!(tanzania.min, 100.0);
+        assert_eq!(tanzania.max, 188.0);
+        assert_eq!(report["USA"].count, 2);
+    }
+
+    #[test]
+    fn groups_by_month_and_year() {
+        let sales = parse_sales(SALES.as_bytes()).unwrap();
+        let months: Vec<String> = report(&sales, GroupBy::Month).into_keys().collect();
+        assert_eq!(months, vec!["2022-03", "2022-05", "2022-09", "2023-09"]);
+
+        let years = report(&sales, GroupBy::Year);
+        assert_eq!(years["2022"].sum, 311.0);
+        assert_eq!(years["2023"].max, 7.0);
+    }
+
+    #[test]
+    fn uses_headers_and_quoted_fields() {
+        let sales = "Amount,Country,Date\r\n\r\n5,\"Korea, South\",2022-01-01\r\n";
+        let sales = parse_sales(sales.as_bytes()).unwrap();
+        assert_eq!(sales[0].country, "Korea, South");
+        assert_eq!(sales[0].amount, 5.0);
+    }
+
+    #[test]
+    fn reports_bad_rows() {
+        let bad = "Country,Date,Amount\nUSA,2022-01-01,5\nUSA,2022-13-01,5\n";
+        match parse_sales(bad.as_bytes()) {
+            Err(SalesError::Parse { line, column, .. }) => assert_eq!((line, column), (3, "Date")),
+            other => panic!("expected parse error, got {:?}", other),
+        }
+
+        let missing = "Country,Date\nUSA,2022-01-01\n";
+        assert!(matches!(
+            parse_sales(missing.as_bytes()),
+            Err(SalesError::Csv(CsvError::MissingColumn(_)))
+        ));
+    }
+
+    #[test]
+    fn parses_dates() {
+        let date: Date = "2024-02-29".parse().unwrap();
+        assert_eq!((date.year, date.month, date.day), (2024, 2, 29));
+        assert!("2000-02-29".parse::<Date>().is_ok());
+        for bad in [
+            "2023-02-29",
+            "1900-02-29",
+            "2022-04-31",
+            "2022-00-10",
+            "4294967295-01-01",
+        ] {
+            assert!(bad.parse::<Date>().is_err(), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn reads_files_concurrently_in_order() {
+        let paths = vec![
+            PathBuf::from("./data/sales.csv"),
+            PathBuf::from("./data/sales_2023.csv"),
+        ];
+        let merged = async_std::task::block_on(read_sales_concurrently(&paths)).unwrap();
+
+        let mut expected = read_sales(&paths[0]).unwrap();
+        expected.extend(read_sales(&paths[1]).unwrap());
+        assert_eq!(merged, expected);
+
+        let missing = [PathBuf::from("./data/missing.csv")];
+        assert!(async_std::task::block_on(read_sales_concurrently(&missing)).is_err());
+    }
+}
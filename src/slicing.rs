@@ -0,0 +1,333 @@
+// Python style string indexing and slicing, `s[1]`, `s[-3:]`, `s[::-1]`
+// Rust's `&s[2..]` takes byte offsets and panics inside a multi-byte char, Python indexes chars.
+// Here every function takes a `Unit` to index bytes, chars or graphemes (what a user sees as
+// one character, "é" written as e + U+0301 is 2 chars but 1 grapheme) and never panics.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+use thiserror::Error;
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::utf8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    Byte,
+    Char,
+    Grapheme,
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Byte => write!(f, "byte"),
+            Unit::Char => write!(f, "char"),
+            Unit::Grapheme => write!(f, "grapheme"),
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SliceError {
+    #[error("{unit} index {index} out of range, length is {len}")]
+    IndexOutOfRange {
+        index: isize,
+        len: usize,
+        unit: Unit,
+    },
+    #[error("slice step cannot be zero")]
+    ZeroStep,
+    #[error("invalid slice '{0}', expected start:stop:step")]
+    InvalidSlice(String),
+    #[error("{0}")]
+    NotCharBoundary(String),
+    #[error("sliced bytes are not valid UTF-8: {0}")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+}
+
+/// Python's slice object, `"1:-1"`, `"::-1"` and `"::2"` parse into one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slice {
+    pub start: Option<isize>,
+    pub stop: Option<isize>,
+    pub step: isize,
+}
+
+impl Slice {
+    pub fn new(start: Option<isize>, stop: Option<isize>, step: isize) -> Self {
+        Slice { start, stop, step }
+    }
+
+    /// `s[::-1]`
+    pub fn reversed() -> Self {
+        Slice::new(None, None, -1)
+    }
+
+    /// Positions picked out of a sequence of `len` items, same rules as Python's `slice.indices`:
+    /// negative indices count from the end, out of range ones are clamped
+    pub fn indices(&self, len: usize) -> Result<Vec<usize>, SliceError> {
+        let step = self.step;
+        if step == 0 {
+            return Err(SliceError::ZeroStep);
+        }
+        let len = len as isize;
+        let clamp = |index: isize| {
+            let index = if index < 0 { index + len } else { index };
+            match step > 0 {
+                true => index.clamp(0, len),
+                false => index.clamp(-1, len - 1),
+            }
+        };
+        let (start, stop) = match step > 0 {
+            true => (self.start.map_or(0, clamp), self.stop.map_or(len, clamp)),
+            false => (
+                self.start.map_or(len - 1, clamp),
+                self.stop.map_or(-1, clamp),
+            ),
+        };
+
+        let mut indices = Vec::new();
+        let mut next = Some(start);
+        // a huge step overflows past the end, which ends the slice like it does in Python
+        while let Some(i) = next.filter(|&i| (step > 0 && i < stop) || (step < 0 && i > stop)) {
+            indices.push(i as usize);
+            next = i.checked_add(step);
+        }
+        Ok(indices)
+    }
+}
+
+impl FromStr for Slice {
+    type Err = SliceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SliceError::InvalidSlice(s.to_string());
+        let parts: Vec<&str> = s.split(':').map(str::trim).collect();
+        if parts.len() < 2 || parts.len() > 3 {
+            return Err(invalid());
+        }
+        let parse = |part: &str| match part {
+            "" => Ok(None),
+            _ => part.parse().map(Some).map_err(|_| invalid()),
+        };
+        let step = match parts.get(2) {
+            Some(part) => parse(part)?.unwrap_or(1),
+            None => 1,
+        };
+        Ok(Slice::new(parse(parts[0])?, parse(parts[1])?, step))
+    }
+}
+
+impl fmt::Display for Slice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let show = |index: Option<isize>| index.map_or(String::new(), |i| i.to_string());
+        write!(f, "{}:{}", show(self.start), show(self.stop))?;
+        match self.step {
+            1 => Ok(()),
+            step => write!(f, ":{}", step),
+        }
+    }
+}
+
+// byte range of every byte, char or grapheme
+fn units(s: &str, unit: Unit) -> Vec<Range<usize>> {
+    match unit {
+        Unit::Byte => (0..s.len()).map(|i| i..i + 1).collect(),
+        Unit::Char => s.char_indices().map(|(i, c)| i..i + c.len_utf8()).collect(),
+        Unit::Grapheme => s
+            .grapheme_indices(true)
+            .map(|(i, g)| i..i + g.len())
+            .collect(),
+    }
+}
+
+/// Number of bytes, chars or graphemes
+pub fn len(s: &str, unit: Unit) -> usize {
+    match unit {
+        Unit::Byte => s.len(),
+        Unit::Char => s.chars().count(),
+        Unit::Grapheme => s.graphemes(true).count(),
+    }
+}
+
+/// `s[index]`, a byte only if it is a whole (ascii) char
+pub fn index(s: &str, index: isize, unit: Unit) -> Result<&str, SliceError> {
+    let units = units(s, unit);
+    let position = if index < 0 {
+        index + units.len() as isize
+    } else {
+        index
+    };
+    let range = usize::try_from(position)
+        .ok()
+        .and_then(|i| units.get(i))
+        .ok_or(SliceError::IndexOutOfRange {
+            index,
+            len: units.len(),
+            unit,
+        })?;
+    boundary(s, range.start)?;
+    boundary(s, range.end)?;
+    Ok(&s[range.clone()])
+}
+
+/// `s[start:stop:step]`, borrowed when the step is 1
+pub fn slice<'a>(s: &'a str, slice: &Slice, unit: Unit) -> Result<Cow<'a, str>, SliceError> {
+    let units = units(s, unit);
+    let indices = slice.indices(units.len())?;
+    let (Some(&first), Some(&last)) = (indices.first(), indices.last()) else {
+        return Ok(Cow::Borrowed(""));
+    };
+
+    if slice.step == 1 {
+        let range = units[first].start..units[last].end;
+        boundary(s, range.start)?;
+        boundary(s, range.end)?;
+        return Ok(Cow::Borrowed(&s[range]));
+    }
+    let bytes: Vec<u8> = indices
+        .iter()
+        .flat_map(|&i| s.as_bytes()[units[i].clone()].iter().copied())
+        .collect();
+    Ok(Cow::Owned(String::from_utf8(bytes)?))
+}
+
+fn boundary(s: &str, index: usize) -> Result<(), SliceError> {
+    match utf8::slice_error(s, index) {
+        Some(error) => Err(SliceError::NotCharBoundary(error)),
+        None => Ok(()),
+    }
+}
+
+/// What `len` means depends on the language, Rust counts bytes and Python counts chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sizes {
+    pub bytes: usize,
+    pub chars: usize,
+    pub graphemes: usize,
+    /// the String itself (pointer, capacity, length) plus its heap bytes
+    pub memsize: usize,
+}
+
+pub fn sizes(s: &str) -> Sizes {
+    Sizes {
+        bytes: s.len(),
+        chars: len(s, Unit::Char),
+        graphemes: len(s, Unit::Grapheme),
+        memsize: std::mem::size_of::<String>() + s.len(),
+    }
+}
+
+impl fmt::Display for Sizes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "length: {}, chars: {}, memsize: {}",
+            self.bytes, self.chars, self.memsize
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn py(s: &str, slice_: &str, unit: Unit) -> Result<String, SliceError> {
+        slice(s, &slice_.parse()?, unit).map(Cow::into_owned)
+    }
+
+    // expected values are from Python 3.11
+    #[test]
+    fn slices_chars_like_python() {
+        let uni = "Héllǒ";
+        assert_eq!(py(uni, "2:", Unit::Char).unwrap(), "llǒ");
+        assert_eq!(py(uni, "::-1", Unit::Char).unwrap(), "ǒlléH");
+        assert_eq!(py(uni, "-2:", Unit::Char).unwrap(), "lǒ");
+        assert_eq!(py(uni, "::2", Unit::Char).unwrap(), "Hlǒ");
+        assert_eq!(py(uni, "1:-1", Unit::Char).unwrap(), "éll");
+        assert_eq!(py(uni, "-100:100", Unit::Char).unwrap(), "Héllǒ");
+        assert_eq!(py(uni, "3:1", Unit::Char).unwrap(), "");
+        assert_eq!(py(uni, "3:0:-1", Unit::Char).unwrap(), "llé");
+        assert_eq!(py(uni, "10::-2", Unit::Char).unwrap(), "ǒlH");
+        assert_eq!(py("", "::-1", Unit::Char).unwrap(), "");
+        assert_eq!(py(uni, "::0", Unit::Char), Err(SliceError::ZeroStep));
+        assert_eq!(py(uni, "1::9223372036854775807", Unit::Char).unwrap(), "é");
+        assert_eq!(py(uni, "::-9223372036854775807", Unit::Char).unwrap(), "ǒ");
+        assert_eq!(py(uni, "::-9223372036854775808", Unit::Char).unwrap(), "ǒ");
+    }
+
+    #[test]
+    fn slices_bytes_without_panicking() {
+        let uni = "Héllǒ";
+        assert_eq!(py(uni, "3:", Unit::Byte).unwrap(), "llǒ");
+        assert_eq!(
+            py(uni, "2:", Unit::Byte),
+            Err(SliceError::NotCharBoundary(
+                "byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3) of `Héllǒ`"
+                    .to_string()
+            ))
+        );
+        assert!(matches!(
+            py(uni, "::-1", Unit::Byte),
+            Err(SliceError::InvalidUtf8(_))
+        ));
+        assert_eq!(py("Hello", "::-2", Unit::Byte).unwrap(), "olH");
+    }
+
+    #[test]
+    fn slices_graphemes() {
+        let combined = "he\u{301}llo 👍🏽";
+        assert_eq!(len(combined, Unit::Char), 9);
+        assert_eq!(len(combined, Unit::Grapheme), 7);
+        assert_eq!(
+            py(combined, "::-1", Unit::Grapheme).unwrap(),
+            "👍🏽 olle\u{301}h"
+        );
+        assert_eq!(py(combined, ":2", Unit::Grapheme).unwrap(), "he\u{301}");
+        assert_eq!(index(combined, -1, Unit::Grapheme), Ok("👍🏽"));
+    }
+
+    #[test]
+    fn indexes() {
+        let uni = "Héllǒ";
+        assert_eq!(index(uni, 1, Unit::Char), Ok("é"));
+        assert_eq!(index(uni, -1, Unit::Char), Ok("ǒ"));
+        assert_eq!(index(uni, 0, Unit::Byte), Ok("H"));
+        assert!(matches!(
+            index(uni, 1, Unit::Byte),
+            Err(SliceError::NotCharBoundary(_))
+        ));
+        assert_eq!(
+            index(uni, 5, Unit::Char),
+            Err(SliceError::IndexOutOfRange {
+                index: 5,
+                len: 5,
+                unit: Unit::Char
+            })
+        );
+        assert!(index(uni, -6, Unit::Char).is_err());
+    }
+
+    #[test]
+    fn parses_slices() {
+        assert_eq!("::-1".parse(), Ok(Slice::reversed()));
+        assert_eq!("1:-1".parse(), Ok(Slice::new(Some(1), Some(-1), 1)));
+        assert_eq!(" 2 : ".parse(), Ok(Slice::new(Some(2), None, 1)));
+        assert_eq!(Slice::new(None, Some(3), 2).to_string(), ":3:2");
+        assert!("1".parse::<Slice>().is_err());
+        assert!("a:b".parse::<Slice>().is_err());
+        assert!("1:2:3:4".parse::<Slice>().is_err());
+    }
+
+    #[test]
+    fn compares_sizes() {
+        assert_eq!(
+            sizes("Héllǒ").to_string(),
+            "length: 7, chars: 5, memsize: 31"
+        );
+        assert_eq!(sizes("Hello").memsize, 29);
+    }
+}
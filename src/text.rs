@@ -0,0 +1,125 @@
+// Grapheme and normalization aware text helpers
+// "é" can be one char (U+00E9) or two (e + U+0301 combining acute), they look the same but
+// `==`, `.chars().rev()` and sort + dedup all see different strings. Python has the same problem,
+// `unicodedata.normalize` fixes the comparison but there are no graphemes in the std lib.
+
+use std::fmt;
+
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Form {
+    /// composed, é is U+00E9
+    Nfc,
+    /// decomposed, é is e + U+0301
+    Nfd,
+    /// composed and compatibility chars replaced, ﬁ becomes fi
+    Nfkc,
+    Nfkd,
+}
+
+impl fmt::Display for Form {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Form::Nfc => write!(f, "NFC"),
+            Form::Nfd => write!(f, "NFD"),
+            Form::Nfkc => write!(f, "NFKC"),
+            Form::Nfkd => write!(f, "NFKD"),
+        }
+    }
+}
+
+/// Python's `unicodedata.normalize(form, s)`
+pub fn normalize(s: &str, form: Form) -> String {
+    match form {
+        Form::Nfc => s.nfc().collect(),
+        Form::Nfd => s.nfd().collect(),
+        Form::Nfkc => s.nfkc().collect(),
+        Form::Nfkd => s.nfkd().collect(),
+    }
+}
+
+/// Python's `unicodedata.is_normalized(form, s)`
+pub fn is_normalized(s: &str, form: Form) -> bool {
+    normalize(s, form) == s
+}
+
+/// Same text however it is composed, "é" == "e\u{301}"
+pub fn eq_normalized(a: &str, b: &str) -> bool {
+    a.nfc().eq(b.nfc())
+}
+
+/// Same text after compatibility chars are replaced as well, "ﬁ" == "fi", "①" == "1"
+pub fn eq_compatible(a: &str, b: &str) -> bool {
+    a.nfkc().eq(b.nfkc())
+}
+
+/// User perceived characters, "e\u{301}" and "👍🏽" are one each
+pub fn graphemes(s: &str) -> impl DoubleEndedIterator<Item = &str> {
+    s.graphemes(true)
+}
+
+pub fn count(s: &str) -> usize {
+    graphemes(s).count()
+}
+
+/// Reverses graphemes, `.chars().rev()` moves combining marks onto the wrong letter
+pub fn reverse(s: &str) -> String {
+    graphemes(s).rev().collect()
+}
+
+/// Sorted graphemes without duplicates, compared in NFC so "é" and "e\u{301}" are one entry
+pub fn unique_graphemes(s: &str) -> Vec<String> {
+    let mut unique: Vec<String> = graphemes(&normalize(s, Form::Nfc))
+        .map(str::to_string)
+        .collect();
+    unique.sort();
+    unique.dedup();
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPOSED: &str = "no\u{eb}l";
+    const DECOMPOSED: &str = "noe\u{308}l";
+
+    #[test]
+    fn normalizes() {
+        assert_ne!(COMPOSED, DECOMPOSED);
+        assert_eq!(normalize(DECOMPOSED, Form::Nfc), COMPOSED);
+        assert_eq!(normalize(COMPOSED, Form::Nfd), DECOMPOSED);
+        assert_eq!(normalize("ﬁ①", Form::Nfkc), "fi1");
+        assert_eq!(normalize("ﬁ①", Form::Nfc), "ﬁ①");
+        assert!(is_normalized(COMPOSED, Form::Nfc));
+        assert!(!is_normalized(COMPOSED, Form::Nfd));
+    }
+
+    #[test]
+    fn compares_normalized() {
+        assert!(eq_normalized("\u{e9}", "e\u{301}"));
+        assert!(eq_normalized(COMPOSED, DECOMPOSED));
+        assert!(!eq_normalized("ﬁ", "fi"));
+        assert!(eq_compatible("ﬁ", "fi"));
+        assert!(!eq_normalized("e", "é"));
+    }
+
+    #[test]
+    fn reverses_and_counts_graphemes() {
+        assert_eq!(DECOMPOSED.chars().rev().collect::<String>(), "l\u{308}eon");
+        assert_eq!(reverse(DECOMPOSED), "le\u{308}on");
+        assert_eq!(reverse("👍🏽🇺🇦!"), "!🇺🇦👍🏽");
+        assert_eq!(count(DECOMPOSED), 4);
+        assert_eq!(count("👍🏽🇺🇦"), 2);
+        assert_eq!(DECOMPOSED.chars().count(), 5);
+    }
+
+    #[test]
+    fn dedups_graphemes() {
+        let mixed = format!("{}{}", COMPOSED, DECOMPOSED);
+        assert_eq!(unique_graphemes(&mixed), vec!["l", "n", "o", "\u{eb}"]);
+        assert_eq!(unique_graphemes("baa"), vec!["a", "b"]);
+    }
+}
@@ -0,0 +1,79 @@
+// Logs every `next()` call, to see when lazy iterators actually do their work
+//
+// (1..=3).trace("source").map(|x| x * 10).take(2).collect::<Vec<_>>();
+// source: next() -> Some(1)
+// source: next() -> Some(2)
+//
+// Python would wrap the iterable in a generator that prints before every yield
+
+use std::fmt::Debug;
+
+pub trait Traced: Iterator + Sized {
+    /// Prints every value that passes through
+    fn trace(self, label: impl Into<String>) -> Trace<Self, fn(String)> {
+        self.trace_with(label, |line| println!("{}", line))
+    }
+
+    /// Sends every log line to `sink` instead of stdout
+    fn trace_with<F: FnMut(String)>(self, label: impl Into<String>, sink: F) -> Trace<Self, F> {
+        Trace {
+            iter: self,
+            label: label.into(),
+            sink,
+        }
+    }
+}
+
+impl<I: Iterator> Traced for I {}
+
+pub struct Trace<I, F> {
+    iter: I,
+    label: String,
+    sink: F,
+}
+
+impl<I, F> Iterator for Trace<I, F>
+where
+    I: Iterator,
+    I::Item: Debug,
+    F: FnMut(String),
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        (self.sink)(format!("{}: next() -> {:?}", self.label, item));
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    #[test]
+    fn logs_every_stage_in_order() {
+        let log = RefCell::new(Vec::new());
+        let sink = |line| log.borrow_mut().push(line);
+        let total: i32 = (1..=4)
+            .trace_with("source", sink)
+            .filter(|x| x % 2 == 0)
+            .trace_with("filter", sink)
+            .take(1)
+            .sum();
+        assert_eq!(total, 2);
+        assert_eq!(
+            log.into_inner(),
+            vec![
+                "source: next() -> Some(1)",
+                "source: next() -> Some(2)",
+                "filter: next() -> Some(2)",
+            ]
+        );
+    }
+}
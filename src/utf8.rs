@@ -0,0 +1,178 @@
+// UTF-8 inspector, shows how every char of a string is laid out in bytes
+// Python hides this, `len("é")` is 1 while `len("é".encode())` is 2
+//
+// Uses 1-4 bytes per char, the prefix bits tell how many:
+// 0xxx_xxxx                               - 1 byte,  U+0000 to U+007F
+// 110x_xxxx 10xx_xxxx                     - 2 bytes, U+0080 to U+07FF
+// 1110_xxxx 10xx_xxxx 10xx_xxxx           - 3 bytes, U+0800 to U+FFFF
+// 1111_0xxx 10xx_xxxx 10xx_xxxx 10xx_xxxx - 4 bytes, U+10000 to U+10FFFF
+
+use std::fmt;
+use std::ops::Range;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EncodedChar {
+    pub ch: char,
+    /// byte offsets in the string
+    pub range: Range<usize>,
+}
+
+impl EncodedChar {
+    pub fn code_point(&self) -> String {
+        format!("U+{:04X}", self.ch as u32)
+    }
+
+    pub fn bytes(&self) -> Vec<u8> {
+        let mut buf = [0; 4];
+        self.ch.encode_utf8(&mut buf).as_bytes().to_vec()
+    }
+
+    /// "C3 A9"
+    pub fn hex(&self) -> String {
+        self.bytes()
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Prefix bits split from the payload, "110|00011 10|101001"
+    pub fn binary(&self) -> String {
+        self.bytes()
+            .iter()
+            .map(|b| {
+                // 0 for ascii, 10 for continuation bytes, 110, 1110 or 11110 for the first byte
+                let prefix = b.leading_ones() as usize + 1;
+                let bits = format!("{:08b}", b);
+                format!("{}|{}", &bits[..prefix], &bits[prefix..])
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+pub struct Report<'a> {
+    s: &'a str,
+    pub chars: Vec<EncodedChar>,
+}
+
+impl Report<'_> {
+    /// Every byte offset from 0 to len, and whether a slice can start or end there
+    pub fn boundaries(&self) -> Vec<(usize, bool)> {
+        (0..=self.s.len())
+            .map(|i| (i, self.s.is_char_boundary(i)))
+            .collect()
+    }
+}
+
+pub fn inspect(s: &str) -> Report<'_> {
+    let chars = s
+        .char_indices()
+        .map(|(i, ch)| EncodedChar {
+            ch,
+            range: i..i + ch.len_utf8(),
+        })
+        .collect();
+    Report { s, chars }
+}
+
+/// Why `&s[index..]` would panic, same wording as the panic message, or None if it wouldn't
+pub fn slice_error(s: &str, index: usize) -> Option<String> {
+    if index > s.len() {
+        return Some(format!("byte index {} is out of bounds of `{}`", index, s));
+    }
+    if s.is_char_boundary(index) {
+        return None;
+    }
+    let inside = inspect(s)
+        .chars
+        .into_iter()
+        .find(|c| c.range.contains(&index))?;
+    Some(format!(
+        "byte index {} is not a char boundary; it is inside {:?} (bytes {:?}) of `{}`",
+        index, inside.ch, inside.range, s
+    ))
+}
+
+impl fmt::Display for Report<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<9} {:<5} {:<6} {:<12} binary (prefix|bits)",
+            "code", "char", "bytes", "hex"
+        )?;
+        for c in &self.chars {
+            writeln!(
+                f,
+                "{:<9} {:<5} {:<6} {:<12} {}",
+                c.code_point(),
+                c.ch.escape_debug().to_string(),
+                format!("{:?}", c.range),
+                c.hex(),
+                c.binary()
+            )?;
+        }
+        let inside: Vec<String> = self
+            .boundaries()
+            .into_iter()
+            .filter(|&(_, boundary)| !boundary)
+            .map(|(i, _)| i.to_string())
+            .collect();
+        match inside.is_empty() {
+            true => write!(f, "every byte offset is a char boundary"),
+            false => write!(f, "not char boundaries: {}", inside.join(", ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_one_to_four_bytes() {
+        let report = inspect("AÄ€𝄞");
+        let hex: Vec<String> = report.chars.iter().map(EncodedChar::hex).collect();
+        assert_eq!(hex, vec!["41", "C3 84", "E2 82 AC", "F0 9D 84 9E"]);
+
+        let ranges: Vec<Range<usize>> = report.chars.iter().map(|c| c.range.clone()).collect();
+        assert_eq!(ranges, vec![0..1, 1..3, 3..6, 6..10]);
+        assert_eq!(report.chars[3].code_point(), "U+1D11E");
+    }
+
+    #[test]
+    fn marks_prefix_bits() {
+        let report = inspect("AÄ𝄞");
+        assert_eq!(report.chars[0].binary(), "0|1000001");
+        assert_eq!(report.chars[1].binary(), "110|00011 10|000100");
+        assert_eq!(
+            report.chars[2].binary(),
+            "11110|000 10|011101 10|000100 10|011110"
+        );
+    }
+
+    #[test]
+    fn explains_the_slice_panic() {
+        let uni = "Héllǒ";
+        let inside: Vec<usize> = inspect(uni)
+            .boundaries()
+            .into_iter()
+            .filter(|&(_, boundary)| !boundary)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(inside, vec![2, 6]);
+        assert_eq!(
+            slice_error(uni, 2).unwrap(),
+            "byte index 2 is not a char boundary; it is inside 'é' (bytes 1..3) of `Héllǒ`"
+        );
+        assert_eq!(slice_error(uni, 3), None);
+        assert!(slice_error(uni, 8).unwrap().contains("out of bounds"));
+    }
+
+    #[test]
+    fn displays_a_table() {
+        let report = inspect("é").to_string();
+        assert!(report.contains("U+00E9    é     0..2   C3 A9        110|00011 10|101001"));
+        assert!(report.ends_with("not char boundaries: 1"));
+    }
+}